@@ -1,10 +1,301 @@
-use std::fmt::Debug;
+use std::{cell::Cell, fmt::Debug, rc::Rc};
 
-use crate::scanner::token::Token;
+use crate::{
+    number::Number,
+    scanner::token::{Span, Token},
+};
+
+#[derive(Clone)]
+pub enum Stmt {
+    Expression(Box<Expr>),
+    Print {
+        keyword: Token,
+        value: Box<Expr>,
+    },
+    Var {
+        name: Token,
+        initializer: Option<Box<Expr>>,
+    },
+    Block(Vec<Stmt>),
+    If {
+        condition: Box<Expr>,
+        then_branch: Box<Stmt>,
+        else_branch: Option<Box<Stmt>>,
+    },
+    While {
+        label: Option<Token>,
+        condition: Box<Expr>,
+        body: Box<Stmt>,
+        increment: Option<Box<Expr>>,
+    },
+    Break {
+        keyword: Token,
+        label: Option<Token>,
+    },
+    Continue {
+        keyword: Token,
+        label: Option<Token>,
+    },
+    Function(Rc<FunctionDecl>),
+    Class {
+        name: Token,
+        superclass: Option<Box<Expr>>,
+        methods: Vec<Rc<FunctionDecl>>,
+    },
+    Return {
+        keyword: Token,
+        value: Option<Box<Expr>>,
+    },
+    Match {
+        keyword: Token,
+        scrutinee: Box<Expr>,
+        arms: Vec<MatchArm>,
+    },
+    Error(Span),
+}
+
+impl Debug for Stmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Expression(expr) => write!(f, "(; {expr:?})"),
+            Self::Print { value, .. } => write!(f, "(print {value:?})"),
+            Self::Var { name, initializer } => match initializer {
+                Some(initializer) => write!(f, "(var {} {initializer:?})", name.identifier()),
+                None => write!(f, "(var {})", name.identifier()),
+            },
+            Self::Block(statements) => {
+                write!(f, "(block")?;
+
+                for statement in statements {
+                    write!(f, " {statement:?}")?;
+                }
+
+                write!(f, ")")
+            }
+            Self::If {
+                condition,
+                then_branch,
+                else_branch,
+            } => match else_branch {
+                Some(else_branch) => {
+                    write!(f, "(if {condition:?} {then_branch:?} {else_branch:?})")
+                }
+                None => write!(f, "(if {condition:?} {then_branch:?})"),
+            },
+            Self::While {
+                label,
+                condition,
+                body,
+                increment,
+            } => {
+                write!(f, "(while")?;
+
+                if let Some(label) = label {
+                    write!(f, " {}:", label.identifier())?;
+                }
+
+                write!(f, " {condition:?} {body:?}")?;
+
+                if let Some(increment) = increment {
+                    write!(f, " {increment:?}")?;
+                }
+
+                write!(f, ")")
+            }
+            Self::Break { label, .. } => match label {
+                Some(label) => write!(f, "(break {})", label.identifier()),
+                None => write!(f, "(break)"),
+            },
+            Self::Continue { label, .. } => match label {
+                Some(label) => write!(f, "(continue {})", label.identifier()),
+                None => write!(f, "(continue)"),
+            },
+            Self::Function(declaration) => write!(f, "{declaration:?}"),
+            Self::Class {
+                name,
+                superclass,
+                methods,
+            } => {
+                write!(f, "(class {}", name.identifier())?;
+
+                if let Some(superclass) = superclass {
+                    write!(f, " (< {superclass:?})")?;
+                }
+
+                for method in methods {
+                    write!(f, " {method:?}")?;
+                }
+
+                write!(f, ")")
+            }
+            Self::Return { value, .. } => match value {
+                Some(value) => write!(f, "(return {value:?})"),
+                None => write!(f, "(return)"),
+            },
+            Self::Match {
+                scrutinee, arms, ..
+            } => {
+                write!(f, "(match {scrutinee:?}")?;
+
+                for arm in arms {
+                    write!(f, " {arm:?}")?;
+                }
+
+                write!(f, ")")
+            }
+            Self::Error(span) => write!(f, "(error {span})"),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct MatchArm {
+    pub pattern: Pattern,
+    pub guard: Option<Box<Expr>>,
+    pub body: Stmt,
+}
+
+impl Debug for MatchArm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(arm {:?}", self.pattern)?;
+
+        if let Some(guard) = &self.guard {
+            write!(f, " (if {guard:?})")?;
+        }
+
+        write!(f, " {:?})", self.body)
+    }
+}
+
+#[derive(Clone)]
+pub enum Pattern {
+    Wildcard,
+    Literal {
+        value: Literal,
+        span: Span,
+    },
+    Range {
+        start: Number,
+        end: Number,
+        inclusive: bool,
+        span: Span,
+    },
+    Binding(Token),
+    Class {
+        name: Option<Token>,
+        class: Token,
+        depth: Cell<Option<usize>>,
+    },
+}
+
+impl Debug for Pattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Wildcard => write!(f, "_"),
+            Self::Literal { value, .. } => write!(f, "{value:?}"),
+            Self::Range {
+                start,
+                end,
+                inclusive,
+                ..
+            } => write!(f, "{start}..{}{end}", if *inclusive { "=" } else { "" }),
+            Self::Binding(name) => write!(f, "{}", name.identifier()),
+            Self::Class { name, class, .. } => write!(
+                f,
+                "{}: {}",
+                name.as_ref().map_or("_", Token::identifier),
+                class.identifier()
+            ),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Param {
+    pub name: Token,
+    pub default: Option<Box<Expr>>,
+}
+
+impl Debug for Param {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.default {
+            Some(default) => write!(f, "(= {} {default:?})", self.name.identifier()),
+            None => write!(f, "{}", self.name.identifier()),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct FunctionDecl {
+    pub name: Token,
+    pub params: Vec<Param>,
+    pub rest: Option<Token>,
+    pub body: Vec<Stmt>,
+}
+
+impl Debug for FunctionDecl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(fun {} (", self.name.identifier())?;
+
+        for (i, param) in self.params.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+
+            write!(f, "{param:?}")?;
+        }
+
+        if let Some(rest) = &self.rest {
+            if !self.params.is_empty() {
+                write!(f, " ")?;
+            }
+
+            write!(f, "...{}", rest.identifier())?;
+        }
+
+        write!(f, ")")?;
+
+        for statement in &self.body {
+            write!(f, " {statement:?}")?;
+        }
+
+        write!(f, ")")
+    }
+}
+
+#[derive(Clone)]
+pub enum Argument {
+    Positional(Expr),
+    Spread { ellipsis: Token, value: Expr },
+    Named { name: Token, value: Expr },
+}
+
+impl Argument {
+    pub fn value(&self) -> &Expr {
+        match self {
+            Self::Positional(value) | Self::Spread { value, .. } | Self::Named { value, .. } => {
+                value
+            }
+        }
+    }
+}
+
+impl Debug for Argument {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Positional(value) => write!(f, "{value:?}"),
+            Self::Spread { value, .. } => write!(f, "(... {value:?})"),
+            Self::Named { name, value } => write!(f, "({}: {value:?})", name.identifier()),
+        }
+    }
+}
 
 #[derive(Clone)]
 pub enum Expr {
-    Literal(Literal),
+    Literal {
+        value: Literal,
+        span: Span,
+    },
     Unary {
         operator: Token,
         right: Box<Expr>,
@@ -15,12 +306,80 @@ pub enum Expr {
         right: Box<Expr>,
     },
     Grouping(Box<Expr>),
+    Interpolation(Vec<Expr>),
+    Comma(Vec<Expr>),
+    Conditional {
+        condition: Box<Expr>,
+        then_branch: Box<Expr>,
+        else_branch: Box<Expr>,
+    },
+    Variable {
+        name: Token,
+        depth: Cell<Option<usize>>,
+    },
+    Assign {
+        name: Token,
+        value: Box<Expr>,
+        depth: Cell<Option<usize>>,
+    },
+    CompoundAssign {
+        name: Token,
+        operator: Token,
+        value: Box<Expr>,
+        depth: Cell<Option<usize>>,
+    },
+    Logical {
+        left: Box<Expr>,
+        operator: Token,
+        right: Box<Expr>,
+    },
+    Coalesce {
+        left: Box<Expr>,
+        operator: Token,
+        right: Box<Expr>,
+    },
+    Chain(Box<Expr>),
+    Call {
+        callee: Box<Expr>,
+        paren: Token,
+        arguments: Vec<Argument>,
+    },
+    Get {
+        object: Box<Expr>,
+        name: Token,
+    },
+    OptionalGet {
+        object: Box<Expr>,
+        name: Token,
+    },
+    Set {
+        object: Box<Expr>,
+        name: Token,
+        value: Box<Expr>,
+    },
+    CompoundSet {
+        object: Box<Expr>,
+        name: Token,
+        operator: Token,
+        value: Box<Expr>,
+    },
+    Lambda(Rc<FunctionDecl>),
+    This {
+        keyword: Token,
+        depth: Cell<Option<usize>>,
+    },
+    Super {
+        keyword: Token,
+        method: Token,
+        depth: Cell<Option<usize>>,
+    },
+    Error(Span),
 }
 
 impl Debug for Expr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::Literal(literal) => write!(f, "{literal:?}"),
+            Self::Literal { value, .. } => write!(f, "{value:?}"),
             Self::Unary { operator, right } => write!(f, "(\\{} {right:?})", operator.token_type()),
             Self::Binary {
                 left,
@@ -28,13 +387,118 @@ impl Debug for Expr {
                 right,
             } => write!(f, "(\\{} {left:?} {right:?})", operator.token_type()),
             Self::Grouping(expr) => write!(f, "(group {expr:?})"),
+            Self::Interpolation(parts) => {
+                write!(f, "(str")?;
+
+                for part in parts {
+                    write!(f, " {part:?}")?;
+                }
+
+                write!(f, ")")
+            }
+            Self::Comma(exprs) => {
+                write!(f, "(,")?;
+
+                for expr in exprs {
+                    write!(f, " {expr:?}")?;
+                }
+
+                write!(f, ")")
+            }
+            Self::Conditional {
+                condition,
+                then_branch,
+                else_branch,
+            } => write!(f, "(?: {condition:?} {then_branch:?} {else_branch:?})"),
+            Self::Variable { name, .. } => write!(f, "{}", name.identifier()),
+            Self::Assign { name, value, .. } => {
+                write!(f, "(= {} {value:?})", name.identifier())
+            }
+            Self::CompoundAssign {
+                name,
+                operator,
+                value,
+                ..
+            } => write!(
+                f,
+                "({}= {} {value:?})",
+                operator.lexeme(),
+                name.identifier()
+            ),
+            Self::Logical {
+                left,
+                operator,
+                right,
+            } => write!(f, "(\\{} {left:?} {right:?})", operator.token_type()),
+            Self::Coalesce { left, right, .. } => write!(f, "(?? {left:?} {right:?})"),
+            Self::Chain(expr) => write!(f, "(chain {expr:?})"),
+            Self::Call {
+                callee, arguments, ..
+            } => {
+                write!(f, "(call {callee:?}")?;
+
+                for argument in arguments {
+                    write!(f, " {argument:?}")?;
+                }
+
+                write!(f, ")")
+            }
+            Self::Get { object, name } => write!(f, "(. {object:?} {})", name.identifier()),
+            Self::OptionalGet { object, name } => {
+                write!(f, "(?. {object:?} {})", name.identifier())
+            }
+            Self::Set {
+                object,
+                name,
+                value,
+            } => write!(f, "(= (. {object:?} {}) {value:?})", name.identifier()),
+            Self::CompoundSet {
+                object,
+                name,
+                operator,
+                value,
+            } => write!(
+                f,
+                "({}= (. {object:?} {}) {value:?})",
+                operator.lexeme(),
+                name.identifier()
+            ),
+            Self::Lambda(declaration) => write!(f, "{declaration:?}"),
+            Self::This { .. } => write!(f, "this"),
+            Self::Super { method, .. } => write!(f, "(super {})", method.identifier()),
+            Self::Error(span) => write!(f, "(error {span})"),
+        }
+    }
+}
+
+impl Expr {
+    /// Splits a left-nested chain of binary operators, calls and property
+    /// accesses into its innermost operand and the links applied to it,
+    /// innermost first, so passes can walk long chains without recursing.
+    pub fn unchain(&self) -> (&Expr, Vec<&Expr>) {
+        let mut links = Vec::new();
+        let mut operand = self;
+
+        while let Self::Binary { left: inner, .. }
+        | Self::Logical { left: inner, .. }
+        | Self::Coalesce { left: inner, .. }
+        | Self::Call { callee: inner, .. }
+        | Self::Get { object: inner, .. }
+        | Self::OptionalGet { object: inner, .. } = operand
+        {
+            links.push(operand);
+            operand = inner;
         }
+
+        links.reverse();
+
+        (operand, links)
     }
 }
 
 #[derive(Clone)]
 pub enum Literal {
-    Number(f64),
+    Number(Number),
     String(String),
     Bool(bool),
     Nil,
@@ -0,0 +1,231 @@
+use std::fmt::Write;
+
+use crate::vm::Value;
+
+use super::{ArgumentKind, Chunk, OpCode};
+
+/// Wide enough for the longest opcode name, `OP_DEFINE_GLOBAL_LONG`.
+const NAME_WIDTH: usize = 21;
+
+impl Chunk {
+    pub fn disassemble(&self, name: &str) -> String {
+        let mut out = format!("== {name} ==\n");
+        let mut offset = 0;
+
+        while offset < self.code.len() {
+            offset = self.disassemble_instruction(offset, &mut out);
+        }
+
+        for constant in &self.constants {
+            if let Value::Function(function) = constant {
+                out.push('\n');
+                out.push_str(&function.chunk.disassemble(&function.to_string()));
+            }
+        }
+
+        out
+    }
+
+    fn disassemble_instruction(&self, offset: usize, out: &mut String) -> usize {
+        let _ = write!(out, "{offset:04} ");
+
+        if offset > 0 && self.span(offset).line == self.span(offset - 1).line {
+            out.push_str("   | ");
+        } else {
+            let _ = write!(out, "{:4} ", self.span(offset).line);
+        }
+
+        let op_code = match OpCode::try_from(self.code[offset]) {
+            Ok(op_code) => op_code,
+            Err(byte) => {
+                let _ = writeln!(out, "Unknown opcode {byte}");
+                return offset + 1;
+            }
+        };
+
+        match op_code {
+            OpCode::Constant
+            | OpCode::GetGlobal
+            | OpCode::DefineGlobal
+            | OpCode::SetGlobal
+            | OpCode::GetProperty
+            | OpCode::SetProperty
+            | OpCode::GetSuper
+            | OpCode::Class
+            | OpCode::Method
+            | OpCode::Inherit
+            | OpCode::IsInstance => self.constant_instruction(op_code, offset, out),
+            OpCode::ConstantLong
+            | OpCode::GetGlobalLong
+            | OpCode::DefineGlobalLong
+            | OpCode::SetGlobalLong
+            | OpCode::GetPropertyLong
+            | OpCode::SetPropertyLong
+            | OpCode::GetSuperLong
+            | OpCode::ClassLong
+            | OpCode::MethodLong
+            | OpCode::InheritLong
+            | OpCode::IsInstanceLong => self.constant_long_instruction(op_code, offset, out),
+            OpCode::GetLocal
+            | OpCode::SetLocal
+            | OpCode::GetUpvalue
+            | OpCode::SetUpvalue
+            | OpCode::InRange
+            | OpCode::Call => self.byte_instruction(op_code, offset, out),
+            OpCode::Jump | OpCode::JumpIfFalse | OpCode::JumpIfNil => {
+                self.jump_instruction(op_code, 1, offset, out)
+            }
+            OpCode::JumpIfProvided => self.jump_if_provided_instruction(offset, out),
+            OpCode::Loop => self.jump_instruction(op_code, -1, offset, out),
+            OpCode::CallWith => self.call_with_instruction(offset, out),
+            OpCode::Closure | OpCode::ClosureLong => self.closure_instruction(op_code, offset, out),
+            _ => {
+                let _ = writeln!(out, "{op_code}");
+                offset + 1
+            }
+        }
+    }
+
+    fn constant_instruction(&self, op_code: OpCode, offset: usize, out: &mut String) -> usize {
+        let constant = self.code[offset + 1];
+
+        let _ = writeln!(
+            out,
+            "{:<NAME_WIDTH$} {constant:4} '{}'",
+            op_code.to_string(),
+            self.constants[constant as usize]
+        );
+
+        offset + 2
+    }
+
+    fn constant_long_instruction(&self, op_code: OpCode, offset: usize, out: &mut String) -> usize {
+        let constant = u16::from_be_bytes([self.code[offset + 1], self.code[offset + 2]]);
+
+        let _ = writeln!(
+            out,
+            "{:<NAME_WIDTH$} {constant:4} '{}'",
+            op_code.to_string(),
+            self.constants[constant as usize]
+        );
+
+        offset + 3
+    }
+
+    fn byte_instruction(&self, op_code: OpCode, offset: usize, out: &mut String) -> usize {
+        let slot = self.code[offset + 1];
+
+        let _ = writeln!(out, "{:<NAME_WIDTH$} {slot:4}", op_code.to_string());
+
+        offset + 2
+    }
+
+    fn jump_instruction(
+        &self,
+        op_code: OpCode,
+        sign: isize,
+        offset: usize,
+        out: &mut String,
+    ) -> usize {
+        let jump = u16::from_be_bytes([self.code[offset + 1], self.code[offset + 2]]) as isize;
+        let target = offset as isize + 3 + sign * jump;
+
+        let _ = writeln!(
+            out,
+            "{:<NAME_WIDTH$} {offset:4} -> {target}",
+            op_code.to_string()
+        );
+
+        offset + 3
+    }
+
+    fn jump_if_provided_instruction(&self, offset: usize, out: &mut String) -> usize {
+        let slot = self.code[offset + 1];
+        let jump = u16::from_be_bytes([self.code[offset + 2], self.code[offset + 3]]) as usize;
+        let target = offset + 4 + jump;
+
+        let _ = writeln!(
+            out,
+            "{:<NAME_WIDTH$} {slot:4} -> {target}",
+            OpCode::JumpIfProvided.to_string()
+        );
+
+        offset + 4
+    }
+
+    fn call_with_instruction(&self, offset: usize, out: &mut String) -> usize {
+        let argument_count = self.code[offset + 1];
+
+        let _ = writeln!(
+            out,
+            "{:<NAME_WIDTH$} {argument_count:4}",
+            OpCode::CallWith.to_string()
+        );
+
+        let mut offset = offset + 2;
+
+        for _ in 0..argument_count {
+            match ArgumentKind::try_from(self.code[offset]) {
+                Ok(ArgumentKind::Named) => {
+                    let constant =
+                        u16::from_be_bytes([self.code[offset + 1], self.code[offset + 2]]);
+
+                    let _ = writeln!(
+                        out,
+                        "{offset:04}    | {:NAME_WIDTH$}    named '{}'",
+                        "", self.constants[constant as usize]
+                    );
+
+                    offset += 3;
+                }
+                Ok(ArgumentKind::Spread) => {
+                    let _ = writeln!(out, "{offset:04}    | {:NAME_WIDTH$}    spread", "");
+
+                    offset += 1;
+                }
+                Ok(ArgumentKind::Positional) | Err(_) => {
+                    let _ = writeln!(out, "{offset:04}    | {:NAME_WIDTH$}    positional", "");
+
+                    offset += 1;
+                }
+            }
+        }
+
+        offset
+    }
+
+    fn closure_instruction(&self, op_code: OpCode, offset: usize, out: &mut String) -> usize {
+        let (constant, mut offset) = if op_code == OpCode::ClosureLong {
+            let constant = u16::from_be_bytes([self.code[offset + 1], self.code[offset + 2]]);
+
+            (constant as usize, offset + 3)
+        } else {
+            (self.code[offset + 1] as usize, offset + 2)
+        };
+        let value = &self.constants[constant];
+
+        let _ = writeln!(
+            out,
+            "{:<NAME_WIDTH$} {constant:4} {value}",
+            op_code.to_string()
+        );
+
+        if let Value::Function(function) = value {
+            for _ in 0..function.upvalue_count {
+                let is_local = self.code[offset];
+                let index = self.code[offset + 1];
+
+                let _ = writeln!(
+                    out,
+                    "{offset:04}    | {:NAME_WIDTH$}    {} {index}",
+                    "",
+                    if is_local == 1 { "local" } else { "upvalue" }
+                );
+
+                offset += 2;
+            }
+        }
+
+        offset
+    }
+}
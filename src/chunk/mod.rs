@@ -0,0 +1,81 @@
+mod disassemble;
+mod op_code;
+
+pub use op_code::{ArgumentKind, OpCode};
+
+use std::{collections::HashMap, rc::Rc};
+
+use crate::{
+    number::{BigInt, Number},
+    scanner::token::Span,
+    vm::Value,
+};
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+enum ConstantKey {
+    Integer(i64),
+    Big(Rc<BigInt>),
+    Float(u64),
+    String(Rc<str>),
+}
+
+impl ConstantKey {
+    fn new(value: &Value) -> Option<Self> {
+        match value {
+            Value::Number(Number::Integer(num)) => Some(Self::Integer(*num)),
+            Value::Number(Number::Big(num)) => Some(Self::Big(num.clone())),
+            Value::Number(Number::Float(num)) => Some(Self::Float(num.to_bits())),
+            Value::String(s) => Some(Self::String(s.clone())),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Chunk {
+    code: Vec<u8>,
+    constants: Vec<Value>,
+    interned: HashMap<ConstantKey, usize>,
+    spans: Vec<Span>,
+}
+
+impl Chunk {
+    pub fn write(&mut self, byte: u8, span: Span) {
+        self.code.push(byte);
+        self.spans.push(span);
+    }
+
+    pub fn add_constant(&mut self, value: Value) -> usize {
+        let key = ConstantKey::new(&value);
+
+        if let Some(index) = key.as_ref().and_then(|key| self.interned.get(key)) {
+            return *index;
+        }
+
+        self.constants.push(value);
+
+        let index = self.constants.len() - 1;
+
+        if let Some(key) = key {
+            self.interned.insert(key, index);
+        }
+
+        index
+    }
+
+    pub fn patch(&mut self, offset: usize, byte: u8) {
+        self.code[offset] = byte;
+    }
+
+    pub fn code(&self) -> &[u8] {
+        &self.code
+    }
+
+    pub fn constants(&self) -> &[Value] {
+        &self.constants
+    }
+
+    pub fn span(&self, offset: usize) -> Span {
+        self.spans.get(offset).copied().unwrap_or_default()
+    }
+}
@@ -0,0 +1,259 @@
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(u8)]
+pub enum OpCode {
+    Constant,
+    ConstantLong,
+    Nil,
+    True,
+    False,
+    Pop,
+    Dup,
+    GetLocal,
+    SetLocal,
+    GetGlobal,
+    GetGlobalLong,
+    DefineGlobal,
+    DefineGlobalLong,
+    SetGlobal,
+    SetGlobalLong,
+    GetUpvalue,
+    SetUpvalue,
+    GetProperty,
+    GetPropertyLong,
+    SetProperty,
+    SetPropertyLong,
+    GetSuper,
+    GetSuperLong,
+    Equal,
+    Greater,
+    GreaterEqual,
+    Less,
+    LessEqual,
+    InRange,
+    IsInstance,
+    IsInstanceLong,
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Modulo,
+    Power,
+    BitAnd,
+    BitOr,
+    BitXor,
+    ShiftLeft,
+    ShiftRight,
+    Not,
+    Negate,
+    BitNot,
+    Stringify,
+    Print,
+    Jump,
+    JumpIfFalse,
+    JumpIfNil,
+    JumpIfProvided,
+    Loop,
+    Call,
+    CallWith,
+    Closure,
+    ClosureLong,
+    CloseUpvalue,
+    Return,
+    Class,
+    ClassLong,
+    Inherit,
+    InheritLong,
+    Method,
+    MethodLong,
+}
+
+impl OpCode {
+    const ALL: [OpCode; 64] = [
+        Self::Constant,
+        Self::ConstantLong,
+        Self::Nil,
+        Self::True,
+        Self::False,
+        Self::Pop,
+        Self::Dup,
+        Self::GetLocal,
+        Self::SetLocal,
+        Self::GetGlobal,
+        Self::GetGlobalLong,
+        Self::DefineGlobal,
+        Self::DefineGlobalLong,
+        Self::SetGlobal,
+        Self::SetGlobalLong,
+        Self::GetUpvalue,
+        Self::SetUpvalue,
+        Self::GetProperty,
+        Self::GetPropertyLong,
+        Self::SetProperty,
+        Self::SetPropertyLong,
+        Self::GetSuper,
+        Self::GetSuperLong,
+        Self::Equal,
+        Self::Greater,
+        Self::GreaterEqual,
+        Self::Less,
+        Self::LessEqual,
+        Self::InRange,
+        Self::IsInstance,
+        Self::IsInstanceLong,
+        Self::Add,
+        Self::Subtract,
+        Self::Multiply,
+        Self::Divide,
+        Self::Modulo,
+        Self::Power,
+        Self::BitAnd,
+        Self::BitOr,
+        Self::BitXor,
+        Self::ShiftLeft,
+        Self::ShiftRight,
+        Self::Not,
+        Self::Negate,
+        Self::BitNot,
+        Self::Stringify,
+        Self::Print,
+        Self::Jump,
+        Self::JumpIfFalse,
+        Self::JumpIfNil,
+        Self::JumpIfProvided,
+        Self::Loop,
+        Self::Call,
+        Self::CallWith,
+        Self::Closure,
+        Self::ClosureLong,
+        Self::CloseUpvalue,
+        Self::Return,
+        Self::Class,
+        Self::ClassLong,
+        Self::Inherit,
+        Self::InheritLong,
+        Self::Method,
+        Self::MethodLong,
+    ];
+
+    pub fn long(self) -> Option<Self> {
+        match self {
+            Self::Constant => Some(Self::ConstantLong),
+            Self::GetGlobal => Some(Self::GetGlobalLong),
+            Self::DefineGlobal => Some(Self::DefineGlobalLong),
+            Self::SetGlobal => Some(Self::SetGlobalLong),
+            Self::GetProperty => Some(Self::GetPropertyLong),
+            Self::SetProperty => Some(Self::SetPropertyLong),
+            Self::GetSuper => Some(Self::GetSuperLong),
+            Self::Closure => Some(Self::ClosureLong),
+            Self::Class => Some(Self::ClassLong),
+            Self::Method => Some(Self::MethodLong),
+            Self::Inherit => Some(Self::InheritLong),
+            Self::IsInstance => Some(Self::IsInstanceLong),
+            _ => None,
+        }
+    }
+}
+
+impl TryFrom<u8> for OpCode {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Self::ALL.get(value as usize).copied().ok_or(value)
+    }
+}
+
+impl Display for OpCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Constant => "OP_CONSTANT",
+            Self::ConstantLong => "OP_CONSTANT_LONG",
+            Self::Nil => "OP_NIL",
+            Self::True => "OP_TRUE",
+            Self::False => "OP_FALSE",
+            Self::Pop => "OP_POP",
+            Self::Dup => "OP_DUP",
+            Self::GetLocal => "OP_GET_LOCAL",
+            Self::SetLocal => "OP_SET_LOCAL",
+            Self::GetGlobal => "OP_GET_GLOBAL",
+            Self::GetGlobalLong => "OP_GET_GLOBAL_LONG",
+            Self::DefineGlobal => "OP_DEFINE_GLOBAL",
+            Self::DefineGlobalLong => "OP_DEFINE_GLOBAL_LONG",
+            Self::SetGlobal => "OP_SET_GLOBAL",
+            Self::SetGlobalLong => "OP_SET_GLOBAL_LONG",
+            Self::GetUpvalue => "OP_GET_UPVALUE",
+            Self::SetUpvalue => "OP_SET_UPVALUE",
+            Self::GetProperty => "OP_GET_PROPERTY",
+            Self::GetPropertyLong => "OP_GET_PROPERTY_LONG",
+            Self::SetProperty => "OP_SET_PROPERTY",
+            Self::SetPropertyLong => "OP_SET_PROPERTY_LONG",
+            Self::GetSuper => "OP_GET_SUPER",
+            Self::GetSuperLong => "OP_GET_SUPER_LONG",
+            Self::Equal => "OP_EQUAL",
+            Self::Greater => "OP_GREATER",
+            Self::GreaterEqual => "OP_GREATER_EQUAL",
+            Self::Less => "OP_LESS",
+            Self::LessEqual => "OP_LESS_EQUAL",
+            Self::InRange => "OP_IN_RANGE",
+            Self::IsInstance => "OP_IS_INSTANCE",
+            Self::IsInstanceLong => "OP_IS_INSTANCE_LONG",
+            Self::Add => "OP_ADD",
+            Self::Subtract => "OP_SUBTRACT",
+            Self::Multiply => "OP_MULTIPLY",
+            Self::Divide => "OP_DIVIDE",
+            Self::Modulo => "OP_MODULO",
+            Self::Power => "OP_POWER",
+            Self::BitAnd => "OP_BIT_AND",
+            Self::BitOr => "OP_BIT_OR",
+            Self::BitXor => "OP_BIT_XOR",
+            Self::ShiftLeft => "OP_SHIFT_LEFT",
+            Self::ShiftRight => "OP_SHIFT_RIGHT",
+            Self::Not => "OP_NOT",
+            Self::Negate => "OP_NEGATE",
+            Self::BitNot => "OP_BIT_NOT",
+            Self::Stringify => "OP_STRINGIFY",
+            Self::Print => "OP_PRINT",
+            Self::Jump => "OP_JUMP",
+            Self::JumpIfFalse => "OP_JUMP_IF_FALSE",
+            Self::JumpIfNil => "OP_JUMP_IF_NIL",
+            Self::JumpIfProvided => "OP_JUMP_IF_PROVIDED",
+            Self::Loop => "OP_LOOP",
+            Self::Call => "OP_CALL",
+            Self::CallWith => "OP_CALL_WITH",
+            Self::Closure => "OP_CLOSURE",
+            Self::ClosureLong => "OP_CLOSURE_LONG",
+            Self::CloseUpvalue => "OP_CLOSE_UPVALUE",
+            Self::Return => "OP_RETURN",
+            Self::Class => "OP_CLASS",
+            Self::ClassLong => "OP_CLASS_LONG",
+            Self::Inherit => "OP_INHERIT",
+            Self::InheritLong => "OP_INHERIT_LONG",
+            Self::Method => "OP_METHOD",
+            Self::MethodLong => "OP_METHOD_LONG",
+        };
+
+        write!(f, "{name}")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(u8)]
+pub enum ArgumentKind {
+    Positional,
+    Spread,
+    Named,
+}
+
+impl TryFrom<u8> for ArgumentKind {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Positional),
+            1 => Ok(Self::Spread),
+            2 => Ok(Self::Named),
+            _ => Err(value),
+        }
+    }
+}
@@ -0,0 +1,1136 @@
+use std::rc::Rc;
+
+use crate::{
+    ast::{Argument, Expr, FunctionDecl, Literal, MatchArm, Pattern, Stmt},
+    chunk::{ArgumentKind, OpCode},
+    error::{CompilerError, Error},
+    result::Result,
+    scanner::token::{Span, Token, TokenType},
+    vm::{Function, Parameter, Value},
+};
+
+const MAX_LOCALS: usize = u8::MAX as usize + 1;
+const MAX_UPVALUES: usize = u8::MAX as usize + 1;
+const MAX_ARGUMENTS: usize = u8::MAX as usize;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FunctionType {
+    Script,
+    Function,
+    Method,
+    Initializer,
+}
+
+struct Local {
+    name: String,
+    depth: Option<usize>,
+    is_captured: bool,
+}
+
+struct Loop {
+    label: Option<String>,
+    scope_depth: usize,
+    breaks: Vec<usize>,
+    continues: Vec<usize>,
+}
+
+struct UpvalueRef {
+    index: u8,
+    is_local: bool,
+}
+
+struct FunctionState {
+    function: Function,
+    function_type: FunctionType,
+    locals: Vec<Local>,
+    upvalues: Vec<UpvalueRef>,
+    loops: Vec<Loop>,
+    scope_depth: usize,
+}
+
+impl FunctionState {
+    fn new(function_type: FunctionType, name: Option<String>) -> Self {
+        let receiver = match function_type {
+            FunctionType::Method | FunctionType::Initializer => "this",
+            FunctionType::Script | FunctionType::Function => "",
+        };
+
+        Self {
+            function: Function {
+                name,
+                ..Function::default()
+            },
+            function_type,
+            locals: vec![Local {
+                name: receiver.into(),
+                depth: Some(0),
+                is_captured: false,
+            }],
+            upvalues: Vec::new(),
+            loops: Vec::new(),
+            scope_depth: 0,
+        }
+    }
+
+    fn resolve_local(&self, name: &str) -> Option<u8> {
+        self.locals
+            .iter()
+            .rposition(|local| local.name == name)
+            .map(|slot| slot as u8)
+    }
+}
+
+pub struct Compiler {
+    states: Vec<FunctionState>,
+    chains: Vec<Vec<usize>>,
+    span: Span,
+}
+
+impl Compiler {
+    pub fn new() -> Self {
+        Self {
+            states: Vec::new(),
+            chains: Vec::new(),
+            span: Span::default(),
+        }
+    }
+
+    pub fn compile(mut self, statements: &[Stmt]) -> Result<Rc<Function>> {
+        self.states
+            .push(FunctionState::new(FunctionType::Script, None));
+
+        for statement in statements {
+            self.statement(statement)?;
+        }
+
+        let (function, _) = self.end_function();
+
+        Ok(Rc::new(function))
+    }
+
+    fn statement(&mut self, stmt: &Stmt) -> Result<()> {
+        match stmt {
+            Stmt::Expression(expr) => {
+                self.expression(expr)?;
+                self.emit_op(OpCode::Pop);
+            }
+            Stmt::Print { keyword, value } => {
+                self.expression(value)?;
+                self.span = keyword.span();
+                self.emit_op(OpCode::Print);
+            }
+            Stmt::Var { name, initializer } => {
+                self.span = name.span();
+
+                let global = self.declare_variable(name)?;
+
+                match initializer {
+                    Some(initializer) => self.expression(initializer)?,
+                    None => self.emit_op(OpCode::Nil),
+                }
+
+                self.define_variable(global)?;
+            }
+            Stmt::Block(statements) => {
+                self.begin_scope();
+
+                for statement in statements {
+                    self.statement(statement)?;
+                }
+
+                self.end_scope();
+            }
+            Stmt::If { .. } => {
+                let mut branch = Some(stmt);
+                let mut else_jumps = Vec::new();
+
+                while let Some(Stmt::If {
+                    condition,
+                    then_branch,
+                    else_branch,
+                }) = branch
+                {
+                    self.expression(condition)?;
+
+                    let then_jump = self.emit_jump(OpCode::JumpIfFalse);
+                    self.emit_op(OpCode::Pop);
+                    self.statement(then_branch)?;
+
+                    else_jumps.push(self.emit_jump(OpCode::Jump));
+                    self.patch_jump(then_jump)?;
+                    self.emit_op(OpCode::Pop);
+
+                    branch = else_branch.as_deref();
+                }
+
+                if let Some(else_branch) = branch {
+                    self.statement(else_branch)?;
+                }
+
+                for else_jump in else_jumps {
+                    self.patch_jump(else_jump)?;
+                }
+            }
+            Stmt::While {
+                label,
+                condition,
+                body,
+                increment,
+            } => {
+                let loop_start = self.chunk_len();
+
+                self.expression(condition)?;
+
+                let exit_jump = self.emit_jump(OpCode::JumpIfFalse);
+                self.emit_op(OpCode::Pop);
+
+                let scope_depth = self.state().scope_depth;
+                self.state_mut().loops.push(Loop {
+                    label: label.as_ref().map(|label| label.identifier().into()),
+                    scope_depth,
+                    breaks: Vec::new(),
+                    continues: Vec::new(),
+                });
+
+                self.statement(body)?;
+
+                let Loop {
+                    breaks, continues, ..
+                } = self
+                    .state_mut()
+                    .loops
+                    .pop()
+                    .expect("the loop was pushed above");
+
+                for jump in continues {
+                    self.patch_jump(jump)?;
+                }
+
+                if let Some(increment) = increment {
+                    self.expression(increment)?;
+                    self.emit_op(OpCode::Pop);
+                }
+
+                self.emit_loop(loop_start)?;
+
+                self.patch_jump(exit_jump)?;
+                self.emit_op(OpCode::Pop);
+
+                for jump in breaks {
+                    self.patch_jump(jump)?;
+                }
+            }
+            Stmt::Match {
+                keyword,
+                scrutinee,
+                arms,
+            } => {
+                self.begin_scope();
+                self.expression(scrutinee)?;
+                self.span = keyword.span();
+                self.add_local(keyword.lexeme(), keyword)?;
+                self.mark_initialized();
+
+                let slot = (self.state().locals.len() - 1) as u8;
+                let mut end_jumps = Vec::with_capacity(arms.len());
+
+                for arm in arms {
+                    end_jumps.push(self.match_arm(arm, slot)?);
+                }
+
+                for jump in end_jumps {
+                    self.patch_jump(jump)?;
+                }
+
+                self.end_scope();
+            }
+            Stmt::Break { keyword, label } | Stmt::Continue { keyword, label } => {
+                self.span = keyword.span();
+
+                let state = self.state();
+                let index = state
+                    .loops
+                    .iter()
+                    .rposition(|enclosing| {
+                        label.as_ref().is_none_or(|label| {
+                            enclosing.label.as_deref() == Some(label.identifier())
+                        })
+                    })
+                    .expect("the resolver rejects jumps outside a matching loop");
+                let scope_depth = state.loops[index].scope_depth;
+
+                let op_codes = state
+                    .locals
+                    .iter()
+                    .rev()
+                    .take_while(|local| local.depth.is_none_or(|depth| depth > scope_depth))
+                    .map(|local| {
+                        if local.is_captured {
+                            OpCode::CloseUpvalue
+                        } else {
+                            OpCode::Pop
+                        }
+                    })
+                    .collect::<Vec<_>>();
+
+                for op_code in op_codes {
+                    self.emit_op(op_code);
+                }
+
+                let jump = self.emit_jump(OpCode::Jump);
+                let enclosing = &mut self.state_mut().loops[index];
+
+                if *keyword.token_type() == TokenType::Break {
+                    enclosing.breaks.push(jump);
+                } else {
+                    enclosing.continues.push(jump);
+                }
+            }
+            Stmt::Function(declaration) => {
+                self.span = declaration.name.span();
+
+                let global = self.declare_variable(&declaration.name)?;
+                self.mark_initialized();
+
+                self.function(declaration, FunctionType::Function)?;
+                self.define_variable(global)?;
+            }
+            Stmt::Class {
+                name,
+                superclass,
+                methods,
+            } => {
+                self.span = name.span();
+
+                let name_constant = self.identifier_constant(name.identifier());
+                let global = self.declare_variable(name)?;
+
+                self.emit_indexed(OpCode::Class, name_constant)?;
+                self.define_variable(global)?;
+
+                if let Some(superclass) = superclass {
+                    self.expression(superclass)?;
+
+                    self.begin_scope();
+                    self.add_local("super", name)?;
+                    self.mark_initialized();
+
+                    self.named_variable(name, None)?;
+
+                    let superclass_name = match superclass.as_ref() {
+                        Expr::Variable { name, .. } => name,
+                        _ => name,
+                    };
+
+                    self.span = superclass_name.span();
+
+                    let constant = self.identifier_constant(superclass_name.identifier());
+                    self.emit_indexed(OpCode::Inherit, constant)?;
+                }
+
+                self.named_variable(name, None)?;
+
+                for method in methods {
+                    self.span = method.name.span();
+
+                    let constant = self.identifier_constant(method.name.identifier());
+                    let function_type = if method.name.identifier() == "init" {
+                        FunctionType::Initializer
+                    } else {
+                        FunctionType::Method
+                    };
+
+                    self.function(method, function_type)?;
+                    self.emit_indexed(OpCode::Method, constant)?;
+                }
+
+                self.emit_op(OpCode::Pop);
+
+                if superclass.is_some() {
+                    self.end_scope();
+                }
+            }
+            Stmt::Return { keyword, value } => {
+                self.span = keyword.span();
+
+                match value {
+                    Some(value) => {
+                        self.expression(value)?;
+                        self.emit_op(OpCode::Return);
+                    }
+                    None => self.emit_return(),
+                }
+            }
+            Stmt::Error(_) => {}
+        }
+
+        Ok(())
+    }
+
+    fn function(&mut self, declaration: &FunctionDecl, function_type: FunctionType) -> Result<()> {
+        self.states.push(FunctionState::new(
+            function_type,
+            Some(declaration.name.identifier().into()),
+        ));
+        self.begin_scope();
+
+        for param in &declaration.params {
+            self.parameter(&param.name)?;
+
+            if let Some(default) = &param.default {
+                let slot = self.state().locals.len() as u8;
+
+                self.span = param.name.span();
+                self.emit_bytes(OpCode::JumpIfProvided, slot);
+                self.emit_byte(0xff);
+                self.emit_byte(0xff);
+
+                let jump = self.chunk_len() - 2;
+
+                self.expression(default)?;
+                self.emit_bytes(OpCode::SetLocal, slot);
+                self.emit_op(OpCode::Pop);
+                self.patch_jump(jump)?;
+            }
+
+            self.state_mut().function.params.push(Parameter {
+                name: param.name.identifier().into(),
+                has_default: param.default.is_some(),
+            });
+            self.add_local(param.name.identifier(), &param.name)?;
+            self.mark_initialized();
+        }
+
+        if let Some(rest) = &declaration.rest {
+            self.parameter(rest)?;
+
+            self.state_mut().function.variadic = true;
+            self.add_local(rest.identifier(), rest)?;
+            self.mark_initialized();
+        }
+
+        for statement in &declaration.body {
+            self.statement(statement)?;
+        }
+
+        let (function, upvalues) = self.end_function();
+        let constant = self.add_constant(Value::Function(Rc::new(function)));
+
+        self.emit_indexed(OpCode::Closure, constant)?;
+
+        for upvalue in upvalues {
+            self.emit_byte(upvalue.is_local as u8);
+            self.emit_byte(upvalue.index);
+        }
+
+        Ok(())
+    }
+
+    fn parameter(&self, name: &Token) -> Result<()> {
+        let function = &self.state().function;
+
+        if function.params.len() + usize::from(function.variadic) == MAX_ARGUMENTS {
+            return Err(Error::Compiler(CompilerError::TooManyParameters {
+                name: name.clone(),
+            }));
+        }
+
+        Ok(())
+    }
+
+    fn expression(&mut self, expr: &Expr) -> Result<()> {
+        match expr {
+            Expr::Literal { value, span } => {
+                self.span = *span;
+                self.literal(value)?;
+            }
+            Expr::Error(_) => self.emit_op(OpCode::Nil),
+            Expr::Grouping(expr) => self.expression(expr)?,
+            Expr::Interpolation(parts) => {
+                for (i, part) in parts.iter().enumerate() {
+                    self.expression(part)?;
+                    self.emit_op(OpCode::Stringify);
+
+                    if i > 0 {
+                        self.emit_op(OpCode::Add);
+                    }
+                }
+            }
+            Expr::Unary { operator, right } => {
+                self.expression(right)?;
+                self.span = operator.span();
+
+                match operator.token_type() {
+                    TokenType::Minus => self.emit_op(OpCode::Negate),
+                    TokenType::Tilde => self.emit_op(OpCode::BitNot),
+                    _ => self.emit_op(OpCode::Not),
+                }
+            }
+            Expr::Binary { .. }
+            | Expr::Logical { .. }
+            | Expr::Coalesce { .. }
+            | Expr::Call { .. }
+            | Expr::Get { .. }
+            | Expr::OptionalGet { .. } => {
+                let (operand, links) = expr.unchain();
+
+                self.expression(operand)?;
+
+                for link in links {
+                    self.link(link)?;
+                }
+            }
+            Expr::CompoundAssign {
+                name,
+                operator,
+                value,
+                ..
+            } => {
+                let (get, set, argument) = self.variable_ops(name)?;
+
+                self.span = name.span();
+                self.emit_indexed(get, argument)?;
+                self.expression(value)?;
+                self.binary_operator(operator);
+                self.span = name.span();
+                self.emit_indexed(set, argument)?;
+            }
+            Expr::Comma(exprs) => {
+                for (i, expr) in exprs.iter().enumerate() {
+                    if i > 0 {
+                        self.emit_op(OpCode::Pop);
+                    }
+
+                    self.expression(expr)?;
+                }
+            }
+            Expr::Conditional {
+                condition,
+                then_branch,
+                else_branch,
+            } => {
+                self.expression(condition)?;
+
+                let then_jump = self.emit_jump(OpCode::JumpIfFalse);
+                self.emit_op(OpCode::Pop);
+                self.expression(then_branch)?;
+
+                let else_jump = self.emit_jump(OpCode::Jump);
+                self.patch_jump(then_jump)?;
+                self.emit_op(OpCode::Pop);
+                self.expression(else_branch)?;
+                self.patch_jump(else_jump)?;
+            }
+            Expr::Chain(expr) => {
+                self.chains.push(Vec::new());
+                self.expression(expr)?;
+
+                for jump in self.chains.pop().unwrap_or_default() {
+                    self.patch_jump(jump)?;
+                }
+            }
+            Expr::Lambda(declaration) => {
+                self.span = declaration.name.span();
+                self.function(declaration, FunctionType::Function)?;
+            }
+            Expr::Variable { name, .. } => self.named_variable(name, None)?,
+            Expr::Assign { name, value, .. } => self.named_variable(name, Some(value))?,
+            Expr::Set {
+                object,
+                name,
+                value,
+            } => {
+                self.expression(object)?;
+                self.expression(value)?;
+                self.span = name.span();
+
+                let constant = self.identifier_constant(name.identifier());
+                self.emit_indexed(OpCode::SetProperty, constant)?;
+            }
+            Expr::CompoundSet {
+                object,
+                name,
+                operator,
+                value,
+            } => {
+                self.expression(object)?;
+                self.span = name.span();
+
+                let constant = self.identifier_constant(name.identifier());
+                self.emit_op(OpCode::Dup);
+                self.emit_indexed(OpCode::GetProperty, constant)?;
+                self.expression(value)?;
+                self.binary_operator(operator);
+                self.span = name.span();
+                self.emit_indexed(OpCode::SetProperty, constant)?;
+            }
+            Expr::This { keyword, .. } => self.named_variable(keyword, None)?,
+            Expr::Super {
+                keyword, method, ..
+            } => {
+                self.span = keyword.span();
+
+                let constant = self.identifier_constant(method.identifier());
+                let this = Token::new(TokenType::This, "this".into(), keyword.span());
+
+                self.named_variable(&this, None)?;
+                self.named_variable(keyword, None)?;
+                self.emit_indexed(OpCode::GetSuper, constant)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn link(&mut self, link: &Expr) -> Result<()> {
+        match link {
+            Expr::Binary {
+                operator, right, ..
+            } => {
+                self.expression(right)?;
+                self.binary_operator(operator);
+            }
+            Expr::Logical {
+                operator, right, ..
+            } => {
+                self.span = operator.span();
+
+                if *operator.token_type() == TokenType::Or {
+                    let else_jump = self.emit_jump(OpCode::JumpIfFalse);
+                    let end_jump = self.emit_jump(OpCode::Jump);
+
+                    self.patch_jump(else_jump)?;
+                    self.emit_op(OpCode::Pop);
+                    self.expression(right)?;
+                    self.patch_jump(end_jump)?;
+                } else {
+                    let end_jump = self.emit_jump(OpCode::JumpIfFalse);
+
+                    self.emit_op(OpCode::Pop);
+                    self.expression(right)?;
+                    self.patch_jump(end_jump)?;
+                }
+            }
+            Expr::Coalesce {
+                operator, right, ..
+            } => {
+                self.span = operator.span();
+
+                let else_jump = self.emit_jump(OpCode::JumpIfNil);
+                let end_jump = self.emit_jump(OpCode::Jump);
+
+                self.patch_jump(else_jump)?;
+                self.emit_op(OpCode::Pop);
+                self.expression(right)?;
+                self.patch_jump(end_jump)?;
+            }
+            Expr::Call {
+                paren, arguments, ..
+            } => {
+                if arguments.len() > MAX_ARGUMENTS {
+                    return Err(Error::Compiler(CompilerError::TooManyArguments {
+                        paren: paren.clone(),
+                    }));
+                }
+
+                let mut kinds = Vec::new();
+
+                for argument in arguments {
+                    self.expression(argument.value())?;
+
+                    match argument {
+                        Argument::Positional(_) => {
+                            kinds.push((ArgumentKind::Positional as u8, paren.span()));
+                        }
+                        Argument::Spread { ellipsis, .. } => {
+                            kinds.push((ArgumentKind::Spread as u8, ellipsis.span()));
+                        }
+                        Argument::Named { name, .. } => {
+                            let constant = self.identifier_constant(name.identifier());
+                            let [high, low] = u16::try_from(constant)
+                                .map_err(|_| {
+                                    Error::Compiler(CompilerError::TooManyConstants {
+                                        span: name.span(),
+                                    })
+                                })?
+                                .to_be_bytes();
+
+                            kinds.extend(
+                                [ArgumentKind::Named as u8, high, low]
+                                    .map(|byte| (byte, name.span())),
+                            );
+                        }
+                    }
+                }
+
+                self.span = paren.span();
+
+                if arguments
+                    .iter()
+                    .all(|argument| matches!(argument, Argument::Positional(_)))
+                {
+                    self.emit_bytes(OpCode::Call, arguments.len() as u8);
+                } else {
+                    self.emit_bytes(OpCode::CallWith, arguments.len() as u8);
+
+                    for (byte, span) in kinds {
+                        self.span = span;
+                        self.emit_byte(byte);
+                    }
+
+                    self.span = paren.span();
+                }
+            }
+            Expr::Get { name, .. } => {
+                self.span = name.span();
+
+                let constant = self.identifier_constant(name.identifier());
+                self.emit_indexed(OpCode::GetProperty, constant)?;
+            }
+            Expr::OptionalGet { name, .. } => {
+                self.span = name.span();
+
+                let constant = self.identifier_constant(name.identifier());
+                let nil_jump = self.emit_jump(OpCode::JumpIfNil);
+                self.emit_indexed(OpCode::GetProperty, constant)?;
+
+                match self.chains.last_mut() {
+                    Some(jumps) => jumps.push(nil_jump),
+                    None => self.patch_jump(nil_jump)?,
+                }
+            }
+            _ => unreachable!("unchain only yields chain links"),
+        }
+
+        Ok(())
+    }
+
+    fn literal(&mut self, literal: &Literal) -> Result<()> {
+        match literal {
+            Literal::Number(num) => self.emit_constant(Value::Number(num.clone()))?,
+            Literal::String(s) => self.emit_constant(Value::String(s.as_str().into()))?,
+            Literal::Bool(true) => self.emit_op(OpCode::True),
+            Literal::Bool(false) => self.emit_op(OpCode::False),
+            Literal::Nil => self.emit_op(OpCode::Nil),
+        }
+
+        Ok(())
+    }
+
+    fn match_arm(&mut self, arm: &MatchArm, slot: u8) -> Result<usize> {
+        self.begin_scope();
+
+        let pattern_jump = match &arm.pattern {
+            Pattern::Wildcard | Pattern::Binding(_) => None,
+            Pattern::Literal {
+                value: literal,
+                span,
+            } => {
+                self.span = *span;
+                self.emit_bytes(OpCode::GetLocal, slot);
+                self.literal(literal)?;
+                self.emit_op(OpCode::Equal);
+
+                Some(self.emit_jump(OpCode::JumpIfFalse))
+            }
+            Pattern::Range {
+                start,
+                end,
+                inclusive,
+                span,
+            } => {
+                self.span = *span;
+                self.emit_bytes(OpCode::GetLocal, slot);
+                self.emit_constant(Value::Number(start.clone()))?;
+                self.emit_constant(Value::Number(end.clone()))?;
+                self.emit_bytes(OpCode::InRange, u8::from(*inclusive));
+
+                Some(self.emit_jump(OpCode::JumpIfFalse))
+            }
+            Pattern::Class { class, .. } => {
+                self.span = class.span();
+                self.emit_bytes(OpCode::GetLocal, slot);
+                self.named_variable(class, None)?;
+
+                let constant = self.identifier_constant(class.identifier());
+                self.emit_indexed(OpCode::IsInstance, constant)?;
+
+                Some(self.emit_jump(OpCode::JumpIfFalse))
+            }
+        };
+
+        if pattern_jump.is_some() {
+            self.emit_op(OpCode::Pop);
+        }
+
+        if let Pattern::Binding(name)
+        | Pattern::Class {
+            name: Some(name), ..
+        } = &arm.pattern
+        {
+            self.span = name.span();
+            self.emit_bytes(OpCode::GetLocal, slot);
+            self.add_local(name.identifier(), name)?;
+            self.mark_initialized();
+        }
+
+        let guard_jump = match &arm.guard {
+            Some(guard) => {
+                self.expression(guard)?;
+
+                let jump = self.emit_jump(OpCode::JumpIfFalse);
+                self.emit_op(OpCode::Pop);
+
+                Some(jump)
+            }
+            None => None,
+        };
+
+        self.statement(&arm.body)?;
+
+        let scope_depth = self.state().scope_depth;
+        let binding_ops = self
+            .state()
+            .locals
+            .iter()
+            .rev()
+            .take_while(|local| local.depth.is_some_and(|depth| depth >= scope_depth))
+            .map(|local| {
+                if local.is_captured {
+                    OpCode::CloseUpvalue
+                } else {
+                    OpCode::Pop
+                }
+            })
+            .collect::<Vec<_>>();
+
+        self.end_scope();
+
+        let end_jump = self.emit_jump(OpCode::Jump);
+
+        if let Some(guard_jump) = guard_jump {
+            self.patch_jump(guard_jump)?;
+            self.emit_op(OpCode::Pop);
+
+            for op_code in binding_ops {
+                self.emit_op(op_code);
+            }
+
+            if let Some(pattern_jump) = pattern_jump {
+                let next_jump = self.emit_jump(OpCode::Jump);
+
+                self.patch_jump(pattern_jump)?;
+                self.emit_op(OpCode::Pop);
+                self.patch_jump(next_jump)?;
+            }
+        } else if let Some(pattern_jump) = pattern_jump {
+            self.patch_jump(pattern_jump)?;
+            self.emit_op(OpCode::Pop);
+        }
+
+        Ok(end_jump)
+    }
+
+    fn binary_operator(&mut self, operator: &Token) {
+        self.span = operator.span();
+
+        match operator.token_type() {
+            TokenType::BangEqual => {
+                self.emit_op(OpCode::Equal);
+                self.emit_op(OpCode::Not);
+            }
+            TokenType::EqualEqual => self.emit_op(OpCode::Equal),
+            TokenType::Greater => self.emit_op(OpCode::Greater),
+            TokenType::GreaterEqual => self.emit_op(OpCode::GreaterEqual),
+            TokenType::Less => self.emit_op(OpCode::Less),
+            TokenType::LessEqual => self.emit_op(OpCode::LessEqual),
+            TokenType::Plus => self.emit_op(OpCode::Add),
+            TokenType::Minus => self.emit_op(OpCode::Subtract),
+            TokenType::Star => self.emit_op(OpCode::Multiply),
+            TokenType::Percent => self.emit_op(OpCode::Modulo),
+            TokenType::StarStar => self.emit_op(OpCode::Power),
+            TokenType::Ampersand => self.emit_op(OpCode::BitAnd),
+            TokenType::Pipe => self.emit_op(OpCode::BitOr),
+            TokenType::Caret => self.emit_op(OpCode::BitXor),
+            TokenType::LessLess => self.emit_op(OpCode::ShiftLeft),
+            TokenType::GreaterGreater => self.emit_op(OpCode::ShiftRight),
+            _ => self.emit_op(OpCode::Divide),
+        }
+    }
+
+    fn named_variable(&mut self, name: &Token, value: Option<&Expr>) -> Result<()> {
+        let (get, set, argument) = self.variable_ops(name)?;
+
+        match value {
+            Some(value) => {
+                self.expression(value)?;
+                self.span = name.span();
+                self.emit_indexed(set, argument)?;
+            }
+            None => {
+                self.span = name.span();
+                self.emit_indexed(get, argument)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn variable_ops(&mut self, name: &Token) -> Result<(OpCode, OpCode, usize)> {
+        let level = self.states.len() - 1;
+        let identifier = name.identifier();
+
+        Ok(if let Some(slot) = self.state().resolve_local(identifier) {
+            (OpCode::GetLocal, OpCode::SetLocal, slot.into())
+        } else if let Some(index) = self.resolve_upvalue(level, name)? {
+            (OpCode::GetUpvalue, OpCode::SetUpvalue, index.into())
+        } else {
+            let constant = self.identifier_constant(identifier);
+            (OpCode::GetGlobal, OpCode::SetGlobal, constant)
+        })
+    }
+
+    fn resolve_upvalue(&mut self, level: usize, name: &Token) -> Result<Option<u8>> {
+        if level == 0 {
+            return Ok(None);
+        }
+
+        if let Some(slot) = self.states[level - 1].resolve_local(name.identifier()) {
+            self.states[level - 1].locals[slot as usize].is_captured = true;
+
+            return self.add_upvalue(level, slot, true, name).map(Some);
+        }
+
+        if let Some(index) = self.resolve_upvalue(level - 1, name)? {
+            return self.add_upvalue(level, index, false, name).map(Some);
+        }
+
+        Ok(None)
+    }
+
+    fn add_upvalue(&mut self, level: usize, index: u8, is_local: bool, name: &Token) -> Result<u8> {
+        let upvalues = &mut self.states[level].upvalues;
+
+        if let Some(existing) = upvalues
+            .iter()
+            .position(|upvalue| upvalue.index == index && upvalue.is_local == is_local)
+        {
+            return Ok(existing as u8);
+        }
+
+        if upvalues.len() == MAX_UPVALUES {
+            return Err(Error::Compiler(CompilerError::TooManyUpvalues {
+                name: name.clone(),
+            }));
+        }
+
+        upvalues.push(UpvalueRef { index, is_local });
+
+        let state = &mut self.states[level];
+        state.function.upvalue_count = state.upvalues.len();
+
+        Ok((state.upvalues.len() - 1) as u8)
+    }
+
+    fn declare_variable(&mut self, name: &Token) -> Result<Option<usize>> {
+        if self.state().scope_depth == 0 {
+            return Ok(Some(self.identifier_constant(name.identifier())));
+        }
+
+        self.add_local(name.identifier(), name)?;
+
+        Ok(None)
+    }
+
+    fn define_variable(&mut self, global: Option<usize>) -> Result<()> {
+        match global {
+            Some(constant) => self.emit_indexed(OpCode::DefineGlobal, constant)?,
+            None => self.mark_initialized(),
+        }
+
+        Ok(())
+    }
+
+    fn add_local(&mut self, name: &str, token: &Token) -> Result<()> {
+        if self.state().locals.len() == MAX_LOCALS {
+            return Err(Error::Compiler(CompilerError::TooManyLocals {
+                name: token.clone(),
+            }));
+        }
+
+        self.state_mut().locals.push(Local {
+            name: name.into(),
+            depth: None,
+            is_captured: false,
+        });
+
+        Ok(())
+    }
+
+    fn mark_initialized(&mut self) {
+        let state = self.state_mut();
+
+        if state.scope_depth == 0 {
+            return;
+        }
+
+        if let Some(local) = state.locals.last_mut() {
+            local.depth = Some(state.scope_depth);
+        }
+    }
+
+    fn begin_scope(&mut self) {
+        self.state_mut().scope_depth += 1;
+    }
+
+    fn end_scope(&mut self) {
+        self.state_mut().scope_depth -= 1;
+
+        loop {
+            let state = self.state();
+
+            let Some(local) = state.locals.last() else {
+                break;
+            };
+
+            if local.depth.is_some_and(|depth| depth <= state.scope_depth) {
+                break;
+            }
+
+            let op_code = if local.is_captured {
+                OpCode::CloseUpvalue
+            } else {
+                OpCode::Pop
+            };
+
+            self.emit_op(op_code);
+            self.state_mut().locals.pop();
+        }
+    }
+
+    fn end_function(&mut self) -> (Function, Vec<UpvalueRef>) {
+        self.emit_return();
+
+        let state = self
+            .states
+            .pop()
+            .expect("a function state is pushed for every compiled function");
+
+        (state.function, state.upvalues)
+    }
+
+    fn identifier_constant(&mut self, name: &str) -> usize {
+        self.add_constant(Value::String(name.into()))
+    }
+
+    fn add_constant(&mut self, value: Value) -> usize {
+        self.state_mut().function.chunk.add_constant(value)
+    }
+
+    fn emit_constant(&mut self, value: Value) -> Result<()> {
+        let constant = self.add_constant(value);
+
+        self.emit_indexed(OpCode::Constant, constant)
+    }
+
+    fn emit_indexed(&mut self, op_code: OpCode, index: usize) -> Result<()> {
+        if let Ok(index) = u8::try_from(index) {
+            self.emit_bytes(op_code, index);
+
+            return Ok(());
+        }
+
+        match (op_code.long(), u16::try_from(index)) {
+            (Some(long), Ok(index)) => {
+                let [high, low] = index.to_be_bytes();
+
+                self.emit_op(long);
+                self.emit_byte(high);
+                self.emit_byte(low);
+
+                Ok(())
+            }
+            _ => Err(Error::Compiler(CompilerError::TooManyConstants {
+                span: self.span,
+            })),
+        }
+    }
+
+    fn emit_return(&mut self) {
+        if self.state().function_type == FunctionType::Initializer {
+            self.emit_bytes(OpCode::GetLocal, 0);
+        } else {
+            self.emit_op(OpCode::Nil);
+        }
+
+        self.emit_op(OpCode::Return);
+    }
+
+    fn emit_jump(&mut self, op_code: OpCode) -> usize {
+        self.emit_op(op_code);
+        self.emit_byte(0xff);
+        self.emit_byte(0xff);
+
+        self.chunk_len() - 2
+    }
+
+    fn patch_jump(&mut self, offset: usize) -> Result<()> {
+        let jump = self.chunk_len() - offset - 2;
+
+        let [high, low] = u16::try_from(jump)
+            .map_err(|_| Error::Compiler(CompilerError::JumpTooLarge { span: self.span }))?
+            .to_be_bytes();
+
+        let chunk = &mut self.state_mut().function.chunk;
+        chunk.patch(offset, high);
+        chunk.patch(offset + 1, low);
+
+        Ok(())
+    }
+
+    fn emit_loop(&mut self, loop_start: usize) -> Result<()> {
+        self.emit_op(OpCode::Loop);
+
+        let offset = self.chunk_len() - loop_start + 2;
+
+        let [high, low] = u16::try_from(offset)
+            .map_err(|_| Error::Compiler(CompilerError::JumpTooLarge { span: self.span }))?
+            .to_be_bytes();
+
+        self.emit_byte(high);
+        self.emit_byte(low);
+
+        Ok(())
+    }
+
+    fn emit_op(&mut self, op_code: OpCode) {
+        self.emit_byte(op_code as u8);
+    }
+
+    fn emit_bytes(&mut self, op_code: OpCode, operand: u8) {
+        self.emit_op(op_code);
+        self.emit_byte(operand);
+    }
+
+    fn emit_byte(&mut self, byte: u8) {
+        let span = self.span;
+
+        self.state_mut().function.chunk.write(byte, span);
+    }
+
+    fn chunk_len(&self) -> usize {
+        self.state().function.chunk.code().len()
+    }
+
+    fn state(&self) -> &FunctionState {
+        self.states
+            .last()
+            .expect("the compiler always has an active function")
+    }
+
+    fn state_mut(&mut self) -> &mut FunctionState {
+        self.states
+            .last_mut()
+            .expect("the compiler always has an active function")
+    }
+}
@@ -0,0 +1,193 @@
+use crate::error::{
+    CompilerError, Error, InterpreterError, ParserError, ResolverError, ResolverWarning,
+    SyntaxError, VmError,
+};
+
+use super::Diagnostic;
+
+impl From<&Error> for Diagnostic {
+    fn from(error: &Error) -> Self {
+        match error {
+            Error::Syntax(e) => e.into(),
+            Error::Parser(e) => e.into(),
+            Error::Resolver(e) => e.into(),
+            Error::Interpreter(e) => e.into(),
+            Error::Compiler(e) => e.into(),
+            Error::Vm(e) => e.into(),
+        }
+    }
+}
+
+impl From<&SyntaxError> for Diagnostic {
+    fn from(error: &SyntaxError) -> Self {
+        let label = match error {
+            SyntaxError::UnexpectedCharacter { .. } => "not valid here",
+            SyntaxError::UnterminatedString { .. } => "string starts here",
+            SyntaxError::EmptyInterpolation { .. } => "expected an expression before this '}'",
+            SyntaxError::InvalidEscape { .. } => "unknown escape",
+            SyntaxError::InvalidNumber { .. } => "invalid number literal",
+            SyntaxError::UnterminatedMultilineComment { .. } => "comment starts here",
+        };
+
+        Diagnostic::error(error.to_string()).with_primary(error.span(), label)
+    }
+}
+
+impl From<&ParserError> for Diagnostic {
+    fn from(error: &ParserError) -> Self {
+        let label = match error {
+            ParserError::UnexpectedEnd { .. } => "expected an expression here".into(),
+            ParserError::ExpectedAnother {
+                expected_token_type,
+                ..
+            } => format!("expected {expected_token_type}"),
+            ParserError::ExpectedOther { .. } => "unexpected token".into(),
+            ParserError::ExpectedIdentifier { .. } => "expected an identifier".into(),
+            ParserError::InvalidAssignmentTarget { .. } => "cannot be assigned to".into(),
+            ParserError::MissingColon { .. } => "this conditional has no ':' branch".into(),
+            ParserError::ExpectedLoop { .. } => "expected 'while' or 'for'".into(),
+            ParserError::ExpectedPattern { .. } => "expected a pattern".into(),
+            ParserError::TooDeep { .. } => "nested too deeply".into(),
+            ParserError::RequiredAfterDefault { .. } => "needs a default value".into(),
+        };
+
+        Diagnostic::error(error.to_string()).with_primary(error.span(), label)
+    }
+}
+
+impl From<&ResolverWarning> for Diagnostic {
+    fn from(warning: &ResolverWarning) -> Self {
+        match warning {
+            ResolverWarning::NonExhaustiveMatch { missing, .. } => {
+                Diagnostic::warning(warning.to_string())
+                    .with_primary(warning.span(), format!("pattern '{missing}' not covered"))
+                    .with_note("add a '_' arm or an arm for each boolean")
+            }
+        }
+    }
+}
+
+impl From<&ResolverError> for Diagnostic {
+    fn from(error: &ResolverError) -> Self {
+        let diagnostic = Diagnostic::error(error.to_string());
+
+        match error {
+            ResolverError::ReadInOwnInitializer { name } => diagnostic
+                .with_primary(name.span(), "read before it is initialized")
+                .with_note("declare the variable first, then assign to it"),
+            ResolverError::AlreadyDeclared { name, previous } => diagnostic
+                .with_primary(name.span(), "declared again here")
+                .with_secondary(*previous, "first declared here"),
+            ResolverError::TopLevelReturn { keyword } => {
+                diagnostic.with_primary(keyword.span(), "not inside a function")
+            }
+            ResolverError::ReturnFromInitializer { keyword } => diagnostic
+                .with_primary(keyword.span(), "returns a value")
+                .with_note("initializers always return 'this'"),
+            ResolverError::ThisOutsideClass { keyword }
+            | ResolverError::SuperOutsideClass { keyword } => {
+                diagnostic.with_primary(keyword.span(), "not inside a class")
+            }
+            ResolverError::SuperWithoutSuperclass { keyword } => {
+                diagnostic.with_primary(keyword.span(), "class has no superclass")
+            }
+            ResolverError::InheritFromSelf { name } => {
+                diagnostic.with_primary(name.span(), "inherits from itself")
+            }
+            ResolverError::OutsideLoop { keyword } => {
+                diagnostic.with_primary(keyword.span(), "not inside a loop")
+            }
+            ResolverError::UnknownLabel { label } => {
+                diagnostic.with_primary(label.span(), "unknown label")
+            }
+        }
+    }
+}
+
+impl From<&InterpreterError> for Diagnostic {
+    fn from(error: &InterpreterError) -> Self {
+        let diagnostic = Diagnostic::error(error.to_string());
+
+        let label = match error {
+            InterpreterError::Io(_) => return diagnostic,
+            InterpreterError::OperandMustBeNumber { .. }
+            | InterpreterError::OperandsMustBeNumbers { .. }
+            | InterpreterError::OperandsMustBeNumbersOrStrings { .. }
+            | InterpreterError::InvalidOperator { .. }
+            | InterpreterError::Arithmetic { .. } => "in this operation".into(),
+            InterpreterError::UndefinedVariable { .. } => "not defined".into(),
+            InterpreterError::NotCallable { .. } => "called here".into(),
+            InterpreterError::OnlyInstancesHaveProperties { .. } => "accessed here".into(),
+            InterpreterError::UndefinedProperty { .. } => "no such property".into(),
+            InterpreterError::SuperclassMustBeClass { .. }
+            | InterpreterError::PatternMustBeClass { .. } => "not a class".into(),
+            InterpreterError::ArityMismatch { expected, .. } => {
+                format!("expected {expected} arguments")
+            }
+            InterpreterError::StackOverflow { .. } => "while calling this".into(),
+            InterpreterError::TooManyArguments { max, .. } => {
+                format!("expected at most {max} arguments")
+            }
+            InterpreterError::MissingArgument { parameter, .. } => {
+                format!("'{parameter}' not given")
+            }
+            InterpreterError::UnknownParameter { .. } => "no such parameter".into(),
+            InterpreterError::DuplicateArgument { .. } => "already given".into(),
+            InterpreterError::SpreadMustBeList { .. } => "not a list".into(),
+            InterpreterError::Native { .. } => "in this call".into(),
+        };
+
+        match error.span() {
+            Some(span) => diagnostic.with_primary(span, label),
+            None => diagnostic,
+        }
+    }
+}
+
+impl From<&CompilerError> for Diagnostic {
+    fn from(error: &CompilerError) -> Self {
+        let label = match error {
+            CompilerError::TooManyConstants { .. } | CompilerError::JumpTooLarge { .. } => {
+                "limit reached here"
+            }
+            CompilerError::TooManyLocals { .. } | CompilerError::TooManyUpvalues { .. } => {
+                "one variable too many"
+            }
+            CompilerError::TooManyParameters { .. } => "one parameter too many",
+            CompilerError::TooManyArguments { .. } => "in this call",
+        };
+
+        Diagnostic::error(error.to_string())
+            .with_primary(error.span(), label)
+            .with_note("the bytecode backend has fixed-size operands")
+    }
+}
+
+impl From<&VmError> for Diagnostic {
+    fn from(error: &VmError) -> Self {
+        let label = match error {
+            VmError::OperandMustBeNumber { .. }
+            | VmError::OperandsMustBeNumbers { .. }
+            | VmError::OperandsMustBeNumbersOrStrings { .. }
+            | VmError::Arithmetic { .. } => "in this operation".into(),
+            VmError::UndefinedVariable { .. } => "not defined".into(),
+            VmError::NotCallable { .. } => "called here".into(),
+            VmError::ArityMismatch { expected, .. } => format!("expected {expected} arguments"),
+            VmError::TooManyArguments { max, .. } => format!("expected at most {max} arguments"),
+            VmError::MissingArgument { parameter, .. } => format!("'{parameter}' not given"),
+            VmError::UnknownParameter { .. } => "no such parameter".into(),
+            VmError::DuplicateArgument { .. } => "already given".into(),
+            VmError::SpreadMustBeList { .. } => "not a list".into(),
+            VmError::Native { .. } => "in this call".into(),
+            VmError::StackOverflow { .. } => "while calling this".into(),
+            VmError::OnlyInstancesHaveProperties { .. } => "accessed here".into(),
+            VmError::UndefinedProperty { .. } => "no such property".into(),
+            VmError::SuperclassMustBeClass { .. } | VmError::PatternMustBeClass { .. } => {
+                "not a class".into()
+            }
+            VmError::InvalidBytecode { .. } => "while executing this".into(),
+        };
+
+        Diagnostic::error(error.to_string()).with_primary(error.span(), label)
+    }
+}
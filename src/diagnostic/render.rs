@@ -0,0 +1,154 @@
+use std::fmt::Write;
+
+use super::{Diagnostic, Label, Severity};
+
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const RED: &str = "\x1b[1;31m";
+const YELLOW: &str = "\x1b[1;33m";
+const BLUE: &str = "\x1b[1;34m";
+
+struct Style {
+    color: bool,
+}
+
+impl Style {
+    fn paint(&self, code: &str, text: &str) -> String {
+        if self.color {
+            format!("{code}{text}{RESET}")
+        } else {
+            text.into()
+        }
+    }
+
+    fn severity(&self, severity: Severity) -> &'static str {
+        match severity {
+            Severity::Error => RED,
+            Severity::Warning => YELLOW,
+        }
+    }
+}
+
+impl Diagnostic {
+    pub fn render(&self, name: &str, source: &str, color: bool) -> String {
+        let style = Style { color };
+        let severity_code = style.severity(self.severity);
+
+        let mut out = format!(
+            "{}{}\n",
+            style.paint(severity_code, &self.severity.to_string()),
+            style.paint(BOLD, &format!(": {}", self.message)),
+        );
+
+        let mut labels: Vec<(&Label, bool)> = self
+            .primary
+            .iter()
+            .map(|label| (label, true))
+            .chain(self.secondary.iter().map(|label| (label, false)))
+            .collect();
+
+        let gutter = labels
+            .iter()
+            .map(|(label, _)| label.span.line.to_string().len())
+            .max()
+            .unwrap_or(1);
+        let pad = " ".repeat(gutter);
+        let bar = style.paint(BLUE, "|");
+
+        if let Some(primary) = &self.primary {
+            let _ = writeln!(
+                out,
+                "{pad}{} {name}:{}",
+                style.paint(BLUE, "-->"),
+                primary.span
+            );
+        }
+
+        if !labels.is_empty() {
+            let _ = writeln!(out, "{pad} {bar}");
+        }
+
+        labels.sort_by_key(|(label, _)| (label.span.line, label.span.column));
+
+        let mut previous_line = None;
+
+        for (label, is_primary) in labels {
+            let line = label.span.line;
+            let text = line_text(source, label.span.start);
+
+            if previous_line != Some(line) {
+                if previous_line.is_some_and(|previous| previous + 1 < line) {
+                    let _ = writeln!(out, "{}", style.paint(BLUE, "..."));
+                }
+
+                let _ = writeln!(
+                    out,
+                    "{} {bar} {text}",
+                    style.paint(BLUE, &format!("{line:>gutter$}"))
+                );
+            }
+
+            previous_line = Some(line);
+
+            let (marker, code) = if is_primary {
+                ("^", severity_code)
+            } else {
+                ("-", BLUE)
+            };
+
+            let _ = writeln!(
+                out,
+                "{pad} {bar} {}{}",
+                indent(text, label.span.column),
+                style.paint(
+                    code,
+                    format!(
+                        "{} {}",
+                        marker.repeat(underline_width(source, text, label)),
+                        label.message
+                    )
+                    .trim_end()
+                )
+            );
+        }
+
+        for note in &self.notes {
+            let _ = writeln!(out, "{pad} {} {note}", style.paint(BOLD, "= note:"));
+        }
+
+        out
+    }
+}
+
+fn line_text(source: &str, offset: usize) -> &str {
+    let offset = offset.min(source.len());
+    let start = source[..offset].rfind('\n').map_or(0, |i| i + 1);
+    let end = source[offset..]
+        .find('\n')
+        .map_or(source.len(), |i| offset + i);
+
+    source[start..end].trim_end_matches('\r')
+}
+
+fn indent(text: &str, column: usize) -> String {
+    text.chars()
+        .take(column.saturating_sub(1))
+        .map(|c| if c == '\t' { '\t' } else { ' ' })
+        .collect()
+}
+
+fn underline_width(source: &str, text: &str, label: &Label) -> usize {
+    let available = text
+        .chars()
+        .count()
+        .saturating_sub(label.span.column.saturating_sub(1));
+
+    let lexeme = source
+        .get(label.span.start..label.span.end)
+        .unwrap_or("")
+        .lines()
+        .next()
+        .unwrap_or("");
+
+    lexeme.chars().count().min(available).max(1)
+}
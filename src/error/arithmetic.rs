@@ -0,0 +1,22 @@
+use std::{error, fmt::Display};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ArithmeticError {
+    DivisionByZero,
+    NotInteger,
+    NegativeShift,
+    TooLarge,
+}
+
+impl Display for ArithmeticError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DivisionByZero => write!(f, "Division by zero"),
+            Self::NotInteger => write!(f, "Operands must be integers"),
+            Self::NegativeShift => write!(f, "Shift amount must not be negative"),
+            Self::TooLarge => write!(f, "Integer result is too large"),
+        }
+    }
+}
+
+impl error::Error for ArithmeticError {}
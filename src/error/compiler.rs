@@ -0,0 +1,52 @@
+use std::{error, fmt::Display};
+
+use crate::scanner::token::{Span, Token};
+
+#[derive(Debug)]
+pub enum CompilerError {
+    TooManyConstants { span: Span },
+    TooManyLocals { name: Token },
+    TooManyUpvalues { name: Token },
+    TooManyParameters { name: Token },
+    TooManyArguments { paren: Token },
+    JumpTooLarge { span: Span },
+}
+
+impl CompilerError {
+    pub fn span(&self) -> Span {
+        match self {
+            Self::TooManyConstants { span } | Self::JumpTooLarge { span } => *span,
+            Self::TooManyLocals { name }
+            | Self::TooManyUpvalues { name }
+            | Self::TooManyParameters { name } => name.span(),
+            Self::TooManyArguments { paren } => paren.span(),
+        }
+    }
+}
+
+impl Display for CompilerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TooManyConstants { .. } => write!(f, "Too many constants in one chunk"),
+            Self::TooManyLocals { name } => write!(
+                f,
+                "Too many local variables in function, '{}'",
+                name.identifier()
+            ),
+            Self::TooManyUpvalues { name } => write!(
+                f,
+                "Too many closure variables in function, '{}'",
+                name.identifier()
+            ),
+            Self::TooManyParameters { name } => write!(
+                f,
+                "Can't have more than 255 parameters, '{}'",
+                name.identifier()
+            ),
+            Self::TooManyArguments { .. } => write!(f, "Can't have more than 255 arguments"),
+            Self::JumpTooLarge { .. } => write!(f, "Too much code to jump over"),
+        }
+    }
+}
+
+impl error::Error for CompilerError {}
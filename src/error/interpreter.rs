@@ -1,14 +1,164 @@
 use std::{error, fmt::Display, io};
 
+use crate::scanner::token::{Span, Token};
+
+use super::{ArithmeticError, NativeError};
+
 #[derive(Debug)]
 pub enum InterpreterError {
     Io(io::Error),
+    OperandMustBeNumber {
+        operator: Token,
+    },
+    OperandsMustBeNumbers {
+        operator: Token,
+    },
+    OperandsMustBeNumbersOrStrings {
+        operator: Token,
+    },
+    InvalidOperator {
+        operator: Token,
+    },
+    Arithmetic {
+        operator: Token,
+        error: ArithmeticError,
+    },
+    UndefinedVariable {
+        name: Token,
+    },
+    NotCallable {
+        paren: Token,
+    },
+    OnlyInstancesHaveProperties {
+        name: Token,
+    },
+    UndefinedProperty {
+        name: Token,
+    },
+    SuperclassMustBeClass {
+        name: Token,
+    },
+    PatternMustBeClass {
+        name: Token,
+    },
+    ArityMismatch {
+        paren: Token,
+        expected: usize,
+        got: usize,
+    },
+    StackOverflow {
+        paren: Token,
+    },
+    TooManyArguments {
+        paren: Token,
+        max: usize,
+        got: usize,
+    },
+    MissingArgument {
+        paren: Token,
+        parameter: String,
+    },
+    UnknownParameter {
+        name: Token,
+    },
+    DuplicateArgument {
+        name: Token,
+    },
+    SpreadMustBeList {
+        ellipsis: Token,
+    },
+    Native {
+        paren: Token,
+        error: NativeError,
+    },
+}
+
+impl InterpreterError {
+    pub fn token(&self) -> Option<&Token> {
+        match self {
+            Self::Io(_) => None,
+            Self::OperandMustBeNumber { operator }
+            | Self::OperandsMustBeNumbers { operator }
+            | Self::OperandsMustBeNumbersOrStrings { operator }
+            | Self::InvalidOperator { operator }
+            | Self::Arithmetic { operator, .. } => Some(operator),
+            Self::UndefinedVariable { name }
+            | Self::OnlyInstancesHaveProperties { name }
+            | Self::UndefinedProperty { name }
+            | Self::SuperclassMustBeClass { name }
+            | Self::PatternMustBeClass { name }
+            | Self::UnknownParameter { name }
+            | Self::DuplicateArgument { name } => Some(name),
+            Self::NotCallable { paren }
+            | Self::ArityMismatch { paren, .. }
+            | Self::StackOverflow { paren }
+            | Self::TooManyArguments { paren, .. }
+            | Self::MissingArgument { paren, .. }
+            | Self::Native { paren, .. } => Some(paren),
+            Self::SpreadMustBeList { ellipsis } => Some(ellipsis),
+        }
+    }
+
+    pub fn span(&self) -> Option<Span> {
+        self.token().map(Token::span)
+    }
 }
 
 impl Display for InterpreterError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Io(e) => write!(f, "Io Error: {e}"),
+            Self::OperandMustBeNumber { operator } => {
+                write!(f, "Operand of '{}' must be a number", operator.lexeme())
+            }
+            Self::OperandsMustBeNumbers { operator } => {
+                write!(f, "Operands of '{}' must be numbers", operator.lexeme())
+            }
+            Self::OperandsMustBeNumbersOrStrings { operator } => write!(
+                f,
+                "Operands of '{}' must be two numbers or two strings",
+                operator.lexeme()
+            ),
+            Self::InvalidOperator { operator } => {
+                write!(f, "Invalid operator '{}'", operator.lexeme())
+            }
+            Self::Arithmetic { error, .. } => write!(f, "{error}"),
+            Self::UndefinedVariable { name } => {
+                write!(f, "Undefined variable '{}'", name.identifier())
+            }
+            Self::NotCallable { .. } => write!(f, "Can only call functions and classes"),
+            Self::OnlyInstancesHaveProperties { name } => write!(
+                f,
+                "Only instances have properties, tried to access '{}'",
+                name.identifier()
+            ),
+            Self::UndefinedProperty { name } => {
+                write!(f, "Undefined property '{}'", name.identifier())
+            }
+            Self::SuperclassMustBeClass { name } => {
+                write!(f, "Superclass '{}' must be a class", name.identifier())
+            }
+            Self::PatternMustBeClass { name } => {
+                write!(f, "Pattern type '{}' must be a class", name.identifier())
+            }
+            Self::ArityMismatch { expected, got, .. } => {
+                write!(f, "Expected {expected} arguments but got {got}")
+            }
+            Self::StackOverflow { .. } => write!(f, "Stack overflow"),
+            Self::TooManyArguments { max, got, .. } => {
+                write!(f, "Expected at most {max} arguments but got {got}")
+            }
+            Self::MissingArgument { parameter, .. } => {
+                write!(f, "Missing argument for parameter '{parameter}'")
+            }
+            Self::UnknownParameter { name } => {
+                write!(f, "Unknown parameter '{}'", name.identifier())
+            }
+            Self::DuplicateArgument { name } => {
+                write!(f, "Multiple values for parameter '{}'", name.identifier())
+            }
+            Self::SpreadMustBeList { .. } => write!(f, "Spread argument must be a list"),
+            Self::Native { error, .. } => write!(f, "{error}"),
         }
     }
 }
@@ -1,18 +1,46 @@
+mod arithmetic;
+mod compiler;
 mod interpreter;
+mod native;
 mod parser;
+mod resolver;
 mod syntax;
+mod vm;
 
 use std::{error, fmt::Display, io};
 
+use crate::scanner::token::Span;
+
+pub use arithmetic::ArithmeticError;
+pub use compiler::CompilerError;
 pub use interpreter::InterpreterError;
+pub use native::NativeError;
 pub use parser::ParserError;
+pub use resolver::{ResolverError, ResolverWarning};
 pub use syntax::SyntaxError;
+pub use vm::VmError;
 
 #[derive(Debug)]
 pub enum Error {
     Interpreter(InterpreterError),
     Syntax(SyntaxError),
     Parser(ParserError),
+    Resolver(ResolverError),
+    Compiler(CompilerError),
+    Vm(VmError),
+}
+
+impl Error {
+    pub fn span(&self) -> Option<Span> {
+        match self {
+            Self::Interpreter(e) => e.span(),
+            Self::Syntax(e) => Some(e.span()),
+            Self::Parser(e) => Some(e.span()),
+            Self::Resolver(e) => Some(e.span()),
+            Self::Compiler(e) => Some(e.span()),
+            Self::Vm(e) => Some(e.span()),
+        }
+    }
 }
 
 impl Display for Error {
@@ -21,6 +49,14 @@ impl Display for Error {
             Self::Interpreter(e) => write!(f, "Interpreter Error: {e}"),
             Self::Syntax(e) => write!(f, "Syntax Error: {e}"),
             Self::Parser(e) => write!(f, "Parser Error: {e}"),
+            Self::Resolver(e) => write!(f, "Resolver Error: {e}"),
+            Self::Compiler(e) => write!(f, "Compiler Error: {e}"),
+            Self::Vm(e) => write!(f, "Runtime Error: {e}"),
+        }?;
+
+        match self.span() {
+            Some(span) => write!(f, " at {span}"),
+            None => Ok(()),
         }
     }
 }
@@ -0,0 +1,24 @@
+use std::{error, fmt::Display};
+
+use crate::number::Number;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum NativeError {
+    ExpectedList { native: &'static str },
+    IndexNotInteger,
+    IndexOutOfRange { index: Number, len: usize },
+}
+
+impl Display for NativeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ExpectedList { native } => write!(f, "Argument to '{native}' must be a list"),
+            Self::IndexNotInteger => write!(f, "List index must be an integer"),
+            Self::IndexOutOfRange { index, len } => {
+                write!(f, "List index {index} is out of range for length {len}")
+            }
+        }
+    }
+}
+
+impl error::Error for NativeError {}
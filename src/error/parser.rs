@@ -1,6 +1,6 @@
 use std::{error, fmt::Display};
 
-use crate::scanner::token::{Token, TokenType};
+use crate::scanner::token::{Span, Token, TokenType};
 
 #[derive(Debug)]
 pub enum ParserError {
@@ -14,29 +14,89 @@ pub enum ParserError {
     ExpectedOther {
         token: Token,
     },
+    ExpectedIdentifier {
+        token: Token,
+    },
+    InvalidAssignmentTarget {
+        token: Token,
+    },
+    MissingColon {
+        question: Token,
+    },
+    ExpectedLoop {
+        token: Token,
+    },
+    ExpectedPattern {
+        token: Token,
+    },
+    TooDeep {
+        token: Token,
+        max_depth: usize,
+    },
+    RequiredAfterDefault {
+        name: Token,
+    },
+}
+
+impl ParserError {
+    pub fn token(&self) -> &Token {
+        match self {
+            Self::UnexpectedEnd { token }
+            | Self::ExpectedAnother { token, .. }
+            | Self::ExpectedOther { token }
+            | Self::ExpectedIdentifier { token }
+            | Self::InvalidAssignmentTarget { token }
+            | Self::MissingColon { question: token }
+            | Self::ExpectedLoop { token }
+            | Self::ExpectedPattern { token }
+            | Self::TooDeep { token, .. }
+            | Self::RequiredAfterDefault { name: token } => token,
+        }
+    }
+
+    pub fn span(&self) -> Span {
+        self.token().span()
+    }
 }
 
 impl Display for ParserError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::UnexpectedEnd { token } => {
-                write!(f, "Expected expression: {}", token.line())
-            }
+            Self::UnexpectedEnd { .. } => write!(f, "Expected expression"),
             Self::ExpectedAnother {
                 expected_token_type,
                 token,
             } => write!(
                 f,
-                "Expected {} instead got {} at line: {}",
+                "Expected {} instead got {}",
                 expected_token_type,
-                token.token_type(),
-                token.line()
+                token.token_type()
             ),
-            Self::ExpectedOther { token } => write!(
+            Self::ExpectedOther { token } => {
+                write!(f, "Unexpected token '{}'", token.lexeme())
+            }
+            Self::ExpectedIdentifier { token } => {
+                write!(f, "Expected identifier instead got {}", token.token_type())
+            }
+            Self::InvalidAssignmentTarget { .. } => write!(f, "Invalid assignment target"),
+            Self::MissingColon { .. } => write!(f, "Expected ':' after then-branch of '?'"),
+            Self::ExpectedPattern { token } => {
+                write!(f, "Expected pattern instead got {}", token.token_type())
+            }
+            Self::ExpectedLoop { token } => {
+                write!(
+                    f,
+                    "Expected loop after label instead got {}",
+                    token.token_type()
+                )
+            }
+            Self::TooDeep { max_depth, .. } => {
+                write!(f, "Nesting exceeds the maximum depth of {max_depth}")
+            }
+            Self::RequiredAfterDefault { name } => write!(
                 f,
-                "Unexpected token {} at line: {}",
-                token.token_type(),
-                token.line()
+                "Required parameter '{}' follows a parameter with a default value",
+                name.identifier()
             ),
         }
     }
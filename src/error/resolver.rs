@@ -0,0 +1,98 @@
+use std::{error, fmt::Display};
+
+use crate::scanner::token::{Span, Token};
+
+#[derive(Debug)]
+pub enum ResolverError {
+    ReadInOwnInitializer { name: Token },
+    AlreadyDeclared { name: Token, previous: Span },
+    TopLevelReturn { keyword: Token },
+    ReturnFromInitializer { keyword: Token },
+    ThisOutsideClass { keyword: Token },
+    SuperOutsideClass { keyword: Token },
+    SuperWithoutSuperclass { keyword: Token },
+    InheritFromSelf { name: Token },
+    OutsideLoop { keyword: Token },
+    UnknownLabel { label: Token },
+}
+
+impl ResolverError {
+    pub fn token(&self) -> &Token {
+        match self {
+            Self::ReadInOwnInitializer { name }
+            | Self::AlreadyDeclared { name, .. }
+            | Self::InheritFromSelf { name }
+            | Self::UnknownLabel { label: name } => name,
+            Self::TopLevelReturn { keyword }
+            | Self::ReturnFromInitializer { keyword }
+            | Self::ThisOutsideClass { keyword }
+            | Self::SuperOutsideClass { keyword }
+            | Self::SuperWithoutSuperclass { keyword }
+            | Self::OutsideLoop { keyword } => keyword,
+        }
+    }
+
+    pub fn span(&self) -> Span {
+        self.token().span()
+    }
+}
+
+impl Display for ResolverError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ReadInOwnInitializer { name } => write!(
+                f,
+                "Can't read local variable '{}' in its own initializer",
+                name.identifier()
+            ),
+            Self::AlreadyDeclared { name, .. } => write!(
+                f,
+                "Variable '{}' is already declared in this scope",
+                name.identifier()
+            ),
+            Self::TopLevelReturn { .. } => write!(f, "Can't return from top-level code"),
+            Self::ReturnFromInitializer { .. } => {
+                write!(f, "Can't return a value from an initializer")
+            }
+            Self::ThisOutsideClass { .. } => write!(f, "Can't use 'this' outside of a class"),
+            Self::SuperOutsideClass { .. } => write!(f, "Can't use 'super' outside of a class"),
+            Self::SuperWithoutSuperclass { .. } => {
+                write!(f, "Can't use 'super' in a class with no superclass")
+            }
+            Self::InheritFromSelf { name } => {
+                write!(f, "Class '{}' can't inherit from itself", name.identifier())
+            }
+            Self::OutsideLoop { keyword } => {
+                write!(f, "Can't use '{}' outside of a loop", keyword.lexeme())
+            }
+            Self::UnknownLabel { label } => {
+                write!(f, "No enclosing loop is labelled '{}'", label.identifier())
+            }
+        }
+    }
+}
+
+impl error::Error for ResolverError {}
+
+#[derive(Debug)]
+pub enum ResolverWarning {
+    NonExhaustiveMatch { keyword: Token, missing: bool },
+}
+
+impl ResolverWarning {
+    pub fn span(&self) -> Span {
+        match self {
+            Self::NonExhaustiveMatch { keyword, .. } => keyword.span(),
+        }
+    }
+}
+
+impl Display for ResolverWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NonExhaustiveMatch { missing, .. } => {
+                write!(f, "Match on booleans doesn't cover '{missing}'")
+            }
+        }
+    }
+}
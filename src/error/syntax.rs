@@ -1,24 +1,45 @@
 use std::{error, fmt::Display};
 
+use crate::scanner::token::Span;
+
 #[derive(Debug)]
 pub enum SyntaxError {
-    UnexpectedCharacter { line: usize, character: char },
-    UnterminatedString { line: usize },
-    InvalidNumber { line: usize },
-    UnterminatedMultilineComment { line: usize },
+    UnexpectedCharacter { span: Span, character: char },
+    UnterminatedString { span: Span },
+    EmptyInterpolation { span: Span },
+    InvalidEscape { span: Span, sequence: String },
+    InvalidNumber { span: Span, lexeme: String },
+    UnterminatedMultilineComment { span: Span },
+}
+
+impl SyntaxError {
+    pub fn span(&self) -> Span {
+        match self {
+            Self::UnexpectedCharacter { span, .. }
+            | Self::UnterminatedString { span }
+            | Self::EmptyInterpolation { span }
+            | Self::InvalidEscape { span, .. }
+            | Self::InvalidNumber { span, .. }
+            | Self::UnterminatedMultilineComment { span } => *span,
+        }
+    }
 }
 
 impl Display for SyntaxError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::UnexpectedCharacter { line, character } => {
-                write!(f, "Unexpected Character at Line {line}: {character}")
+            Self::UnexpectedCharacter { character, .. } => {
+                write!(f, "Unexpected Character: {character}")
+            }
+            Self::UnterminatedString { .. } => write!(f, "Unterminated String"),
+            Self::EmptyInterpolation { .. } => write!(f, "Empty Interpolation"),
+            Self::InvalidEscape { sequence, .. } => {
+                write!(f, "Invalid Escape Sequence: {sequence}")
             }
-            Self::UnterminatedString { line } => write!(f, "Unterminated String at Line: {line}"),
-            Self::UnterminatedMultilineComment { line } => {
-                write!(f, "Unterminated Multiline Comment at Line: {line}")
+            Self::UnterminatedMultilineComment { .. } => {
+                write!(f, "Unterminated Multiline Comment")
             }
-            Self::InvalidNumber { line } => write!(f, "Invalid Number at Line: {line}"),
+            Self::InvalidNumber { lexeme, .. } => write!(f, "Invalid Number: {lexeme}"),
         }
     }
 }
@@ -0,0 +1,172 @@
+use std::{error, fmt::Display};
+
+use crate::scanner::token::Span;
+
+use super::{ArithmeticError, NativeError};
+
+#[derive(Debug)]
+pub enum VmError {
+    OperandMustBeNumber {
+        operator: &'static str,
+        span: Span,
+    },
+    OperandsMustBeNumbers {
+        operator: &'static str,
+        span: Span,
+    },
+    OperandsMustBeNumbersOrStrings {
+        operator: &'static str,
+        span: Span,
+    },
+    Arithmetic {
+        error: ArithmeticError,
+        span: Span,
+    },
+    UndefinedVariable {
+        name: String,
+        span: Span,
+    },
+    NotCallable {
+        span: Span,
+    },
+    ArityMismatch {
+        expected: usize,
+        got: usize,
+        span: Span,
+    },
+    TooManyArguments {
+        max: usize,
+        got: usize,
+        span: Span,
+    },
+    MissingArgument {
+        parameter: String,
+        span: Span,
+    },
+    UnknownParameter {
+        name: String,
+        span: Span,
+    },
+    DuplicateArgument {
+        name: String,
+        span: Span,
+    },
+    SpreadMustBeList {
+        span: Span,
+    },
+    Native {
+        error: NativeError,
+        span: Span,
+    },
+    StackOverflow {
+        span: Span,
+    },
+    OnlyInstancesHaveProperties {
+        name: String,
+        span: Span,
+    },
+    UndefinedProperty {
+        name: String,
+        span: Span,
+    },
+    SuperclassMustBeClass {
+        name: String,
+        span: Span,
+    },
+    PatternMustBeClass {
+        name: String,
+        span: Span,
+    },
+    InvalidBytecode {
+        byte: u8,
+        span: Span,
+    },
+}
+
+impl VmError {
+    pub fn span(&self) -> Span {
+        match self {
+            Self::OperandMustBeNumber { span, .. }
+            | Self::OperandsMustBeNumbers { span, .. }
+            | Self::OperandsMustBeNumbersOrStrings { span, .. }
+            | Self::Arithmetic { span, .. }
+            | Self::UndefinedVariable { span, .. }
+            | Self::NotCallable { span }
+            | Self::ArityMismatch { span, .. }
+            | Self::TooManyArguments { span, .. }
+            | Self::MissingArgument { span, .. }
+            | Self::UnknownParameter { span, .. }
+            | Self::DuplicateArgument { span, .. }
+            | Self::SpreadMustBeList { span }
+            | Self::Native { span, .. }
+            | Self::StackOverflow { span }
+            | Self::OnlyInstancesHaveProperties { span, .. }
+            | Self::UndefinedProperty { span, .. }
+            | Self::SuperclassMustBeClass { span, .. }
+            | Self::PatternMustBeClass { span, .. }
+            | Self::InvalidBytecode { span, .. } => *span,
+        }
+    }
+}
+
+impl Display for VmError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::OperandMustBeNumber { operator, .. } => {
+                write!(f, "Operand of '{operator}' must be a number")
+            }
+            Self::OperandsMustBeNumbers { operator, .. } => {
+                write!(f, "Operands of '{operator}' must be numbers")
+            }
+            Self::OperandsMustBeNumbersOrStrings { operator, .. } => {
+                write!(
+                    f,
+                    "Operands of '{operator}' must be two numbers or two strings"
+                )
+            }
+            Self::Arithmetic { error, .. } => write!(f, "{error}"),
+            Self::UndefinedVariable { name, .. } => {
+                write!(f, "Undefined variable '{name}'")
+            }
+            Self::NotCallable { .. } => {
+                write!(f, "Can only call functions and classes")
+            }
+            Self::ArityMismatch { expected, got, .. } => {
+                write!(f, "Expected {expected} arguments but got {got}")
+            }
+            Self::TooManyArguments { max, got, .. } => {
+                write!(f, "Expected at most {max} arguments but got {got}")
+            }
+            Self::MissingArgument { parameter, .. } => {
+                write!(f, "Missing argument for parameter '{parameter}'")
+            }
+            Self::UnknownParameter { name, .. } => write!(f, "Unknown parameter '{name}'"),
+            Self::DuplicateArgument { name, .. } => {
+                write!(f, "Multiple values for parameter '{name}'")
+            }
+            Self::SpreadMustBeList { .. } => write!(f, "Spread argument must be a list"),
+            Self::Native { error, .. } => write!(f, "{error}"),
+            Self::StackOverflow { .. } => write!(f, "Stack overflow"),
+            Self::OnlyInstancesHaveProperties { name, .. } => {
+                write!(
+                    f,
+                    "Only instances have properties, tried to access '{name}'"
+                )
+            }
+            Self::UndefinedProperty { name, .. } => {
+                write!(f, "Undefined property '{name}'")
+            }
+            Self::SuperclassMustBeClass { name, .. } => {
+                write!(f, "Superclass '{name}' must be a class")
+            }
+            Self::PatternMustBeClass { name, .. } => {
+                write!(f, "Pattern type '{name}' must be a class")
+            }
+            Self::InvalidBytecode { byte, .. } => {
+                write!(f, "Invalid bytecode {byte}")
+            }
+        }
+    }
+}
+
+impl error::Error for VmError {}
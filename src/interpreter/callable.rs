@@ -0,0 +1,25 @@
+use std::fmt::{Debug, Display};
+
+use crate::{result::Result, scanner::token::Token};
+
+use super::{Interpreter, Value};
+
+pub struct Arguments {
+    pub positional: Vec<Value>,
+    pub named: Vec<(Token, Value)>,
+}
+
+impl Arguments {
+    pub fn is_empty(&self) -> bool {
+        self.positional.is_empty() && self.named.is_empty()
+    }
+}
+
+pub trait Callable: Debug + Display {
+    fn call(
+        &self,
+        interpreter: &mut Interpreter,
+        paren: &Token,
+        arguments: Arguments,
+    ) -> Result<Value>;
+}
@@ -0,0 +1,85 @@
+use std::{cell::RefCell, collections::HashMap, fmt::Display, rc::Rc};
+
+use crate::{
+    error::{Error, InterpreterError},
+    result::Result,
+    scanner::token::Token,
+};
+
+use super::{Arguments, Callable, Function, Instance, Interpreter, Value};
+
+#[derive(Debug)]
+pub struct Class {
+    name: String,
+    superclass: Option<Rc<Class>>,
+    methods: HashMap<String, Rc<Function>>,
+}
+
+impl Class {
+    pub fn new(
+        name: String,
+        superclass: Option<Rc<Class>>,
+        methods: HashMap<String, Rc<Function>>,
+    ) -> Self {
+        Self {
+            name,
+            superclass,
+            methods,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn inherits_from(&self, class: &Class) -> bool {
+        std::ptr::eq(self, class)
+            || self
+                .superclass
+                .as_ref()
+                .is_some_and(|superclass| superclass.inherits_from(class))
+    }
+
+    pub fn find_method(&self, name: &str) -> Option<Rc<Function>> {
+        self.methods.get(name).cloned().or_else(|| {
+            self.superclass
+                .as_ref()
+                .and_then(|superclass| superclass.find_method(name))
+        })
+    }
+}
+
+impl Callable for Rc<Class> {
+    fn call(
+        &self,
+        interpreter: &mut Interpreter,
+        paren: &Token,
+        arguments: Arguments,
+    ) -> Result<Value> {
+        let instance = Value::Instance(Rc::new(RefCell::new(Instance::new(self.clone()))));
+
+        match self.find_method("init") {
+            Some(initializer) => {
+                initializer
+                    .bind(instance.clone())
+                    .call(interpreter, paren, arguments)?;
+            }
+            None if !arguments.is_empty() => {
+                return Err(Error::Interpreter(InterpreterError::ArityMismatch {
+                    paren: paren.clone(),
+                    expected: 0,
+                    got: arguments.positional.len() + arguments.named.len(),
+                }));
+            }
+            None => {}
+        }
+
+        Ok(instance)
+    }
+}
+
+impl Display for Class {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
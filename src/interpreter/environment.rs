@@ -0,0 +1,96 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use crate::{
+    error::{Error, InterpreterError},
+    result::Result,
+    scanner::token::Token,
+};
+
+use super::Value;
+
+#[derive(Debug, Default)]
+pub struct Environment {
+    values: HashMap<String, Value>,
+    enclosing: Option<Rc<RefCell<Environment>>>,
+}
+
+impl Environment {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_enclosing(enclosing: Rc<RefCell<Environment>>) -> Self {
+        Self {
+            values: HashMap::new(),
+            enclosing: Some(enclosing),
+        }
+    }
+
+    pub fn define(&mut self, name: &str, value: Value) {
+        self.values.insert(name.into(), value);
+    }
+
+    pub fn get_local(&self, name: &str) -> Option<Value> {
+        self.values.get(name).cloned()
+    }
+
+    pub fn get(&self, name: &Token) -> Result<Value> {
+        if let Some(value) = self.values.get(name.identifier()) {
+            return Ok(value.clone());
+        }
+
+        match &self.enclosing {
+            Some(enclosing) => enclosing.borrow().get(name),
+            None => Err(Error::Interpreter(InterpreterError::UndefinedVariable {
+                name: name.clone(),
+            })),
+        }
+    }
+
+    pub fn assign(&mut self, name: &Token, value: Value) -> Result<()> {
+        if let Some(slot) = self.values.get_mut(name.identifier()) {
+            *slot = value;
+
+            return Ok(());
+        }
+
+        match &self.enclosing {
+            Some(enclosing) => enclosing.borrow_mut().assign(name, value),
+            None => Err(Error::Interpreter(InterpreterError::UndefinedVariable {
+                name: name.clone(),
+            })),
+        }
+    }
+
+    pub fn get_at(&self, distance: usize, name: &Token) -> Result<Value> {
+        self.get_local_at(distance, name.identifier())
+            .ok_or_else(|| {
+                Error::Interpreter(InterpreterError::UndefinedVariable { name: name.clone() })
+            })
+    }
+
+    pub fn get_local_at(&self, distance: usize, name: &str) -> Option<Value> {
+        if distance == 0 {
+            return self.get_local(name);
+        }
+
+        self.enclosing
+            .as_ref()
+            .and_then(|enclosing| enclosing.borrow().get_local_at(distance - 1, name))
+    }
+
+    pub fn assign_at(&mut self, distance: usize, name: &Token, value: Value) -> Result<()> {
+        if distance == 0 {
+            self.values.insert(name.identifier().into(), value);
+
+            return Ok(());
+        }
+
+        match &self.enclosing {
+            Some(enclosing) => enclosing.borrow_mut().assign_at(distance - 1, name, value),
+            None => Err(Error::Interpreter(InterpreterError::UndefinedVariable {
+                name: name.clone(),
+            })),
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use std::{cell::RefCell, fmt::Display, rc::Rc};
+
+use crate::{
+    ast::FunctionDecl,
+    error::{Error, InterpreterError},
+    result::Result,
+    scanner::token::Token,
+};
+
+use super::{Arguments, Callable, Environment, Flow, Interpreter, Value};
+
+#[derive(Debug)]
+pub struct Function {
+    declaration: Rc<FunctionDecl>,
+    closure: Rc<RefCell<Environment>>,
+    is_initializer: bool,
+}
+
+impl Function {
+    pub fn new(
+        declaration: Rc<FunctionDecl>,
+        closure: Rc<RefCell<Environment>>,
+        is_initializer: bool,
+    ) -> Self {
+        Self {
+            declaration,
+            closure,
+            is_initializer,
+        }
+    }
+
+    pub fn bind(&self, instance: Value) -> Self {
+        let mut environment = Environment::with_enclosing(self.closure.clone());
+
+        environment.define("this", instance);
+
+        Self::new(
+            self.declaration.clone(),
+            Rc::new(RefCell::new(environment)),
+            self.is_initializer,
+        )
+    }
+
+    fn bind_arguments(
+        &self,
+        interpreter: &mut Interpreter,
+        paren: &Token,
+        arguments: Arguments,
+        environment: &Rc<RefCell<Environment>>,
+    ) -> Result<()> {
+        let params = &self.declaration.params;
+        let got = arguments.positional.len();
+
+        let mut positional = arguments.positional.into_iter();
+        let mut values: Vec<Option<Value>> = params.iter().map(|_| positional.next()).collect();
+        let rest: Vec<Value> = positional.collect();
+
+        if self.declaration.rest.is_none() && !rest.is_empty() {
+            return Err(Error::Interpreter(InterpreterError::TooManyArguments {
+                paren: paren.clone(),
+                max: params.len(),
+                got,
+            }));
+        }
+
+        for (name, value) in arguments.named {
+            let Some(index) = params
+                .iter()
+                .position(|param| param.name.identifier() == name.identifier())
+            else {
+                return Err(Error::Interpreter(InterpreterError::UnknownParameter {
+                    name,
+                }));
+            };
+
+            if values[index].is_some() {
+                return Err(Error::Interpreter(InterpreterError::DuplicateArgument {
+                    name,
+                }));
+            }
+
+            values[index] = Some(value);
+        }
+
+        if let Some(param) = params.iter().zip(&values).find_map(|(param, value)| {
+            (value.is_none() && param.default.is_none()).then_some(param)
+        }) {
+            return Err(Error::Interpreter(InterpreterError::MissingArgument {
+                paren: paren.clone(),
+                parameter: param.name.identifier().into(),
+            }));
+        }
+
+        for (param, value) in params.iter().zip(values) {
+            let value = match (value, &param.default) {
+                (Some(value), _) => value,
+                (None, Some(default)) => interpreter.evaluate_in(default, environment.clone())?,
+                (None, None) => Value::Nil,
+            };
+
+            environment
+                .borrow_mut()
+                .define(param.name.identifier(), value);
+        }
+
+        if let Some(name) = &self.declaration.rest {
+            environment
+                .borrow_mut()
+                .define(name.identifier(), Value::List(Rc::new(rest)));
+        }
+
+        Ok(())
+    }
+}
+
+impl Callable for Function {
+    fn call(
+        &self,
+        interpreter: &mut Interpreter,
+        paren: &Token,
+        arguments: Arguments,
+    ) -> Result<Value> {
+        let environment = Rc::new(RefCell::new(Environment::with_enclosing(
+            self.closure.clone(),
+        )));
+
+        self.bind_arguments(interpreter, paren, arguments, &environment)?;
+
+        let flow = interpreter.execute_block(&self.declaration.body, environment)?;
+
+        if self.is_initializer {
+            return Ok(self
+                .closure
+                .borrow()
+                .get_local("this")
+                .unwrap_or(Value::Nil));
+        }
+
+        match flow {
+            Flow::Return(value) => Ok(value),
+            Flow::Normal | Flow::Break(_) | Flow::Continue(_) => Ok(Value::Nil),
+        }
+    }
+}
+
+impl Display for Function {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<fn {}>", self.declaration.name.identifier())
+    }
+}
@@ -0,0 +1,55 @@
+use std::{cell::RefCell, collections::HashMap, fmt::Display, rc::Rc};
+
+use crate::{
+    error::{Error, InterpreterError},
+    result::Result,
+    scanner::token::Token,
+};
+
+use super::{Class, Value};
+
+#[derive(Debug)]
+pub struct Instance {
+    class: Rc<Class>,
+    fields: HashMap<String, Value>,
+}
+
+impl Instance {
+    pub fn new(class: Rc<Class>) -> Self {
+        Self {
+            class,
+            fields: HashMap::new(),
+        }
+    }
+
+    pub fn get(instance: &Rc<RefCell<Self>>, name: &Token) -> Result<Value> {
+        if let Some(value) = instance.borrow().fields.get(name.identifier()) {
+            return Ok(value.clone());
+        }
+
+        let method = instance.borrow().class.find_method(name.identifier());
+
+        match method {
+            Some(method) => Ok(Value::Callable(Rc::new(
+                method.bind(Value::Instance(instance.clone())),
+            ))),
+            None => Err(Error::Interpreter(InterpreterError::UndefinedProperty {
+                name: name.clone(),
+            })),
+        }
+    }
+
+    pub fn is_instance_of(&self, class: &Class) -> bool {
+        self.class.inherits_from(class)
+    }
+
+    pub fn set(&mut self, name: &Token, value: Value) {
+        self.fields.insert(name.identifier().into(), value);
+    }
+}
+
+impl Display for Instance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} instance", self.class.name())
+    }
+}
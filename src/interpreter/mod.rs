@@ -0,0 +1,702 @@
+mod callable;
+mod class;
+mod environment;
+mod function;
+mod instance;
+mod native;
+mod value;
+
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    rc::Rc,
+};
+
+pub use callable::{Arguments, Callable};
+pub use class::Class;
+pub use environment::Environment;
+pub use function::Function;
+pub use instance::Instance;
+pub use value::Value;
+
+use crate::{
+    ast::{Argument, Expr, MatchArm, Pattern, Stmt},
+    error::{ArithmeticError, Error, InterpreterError},
+    number::Number,
+    result::Result,
+    scanner::token::{Token, TokenType},
+};
+
+pub enum Flow {
+    Normal,
+    Break(Option<String>),
+    Continue(Option<String>),
+    Return(Value),
+}
+
+const MAX_CALL_DEPTH: usize = 2048;
+
+pub struct Interpreter {
+    globals: Rc<RefCell<Environment>>,
+    environment: Rc<RefCell<Environment>>,
+    call_depth: usize,
+}
+
+impl Interpreter {
+    pub fn new() -> Self {
+        let globals = Rc::new(RefCell::new(Environment::new()));
+
+        for native in native::natives() {
+            globals
+                .borrow_mut()
+                .define(native.name(), Value::Callable(Rc::new(native)));
+        }
+
+        Self {
+            environment: globals.clone(),
+            globals,
+            call_depth: 0,
+        }
+    }
+
+    pub fn interpret(&mut self, statements: &[Stmt]) -> Result<()> {
+        for statement in statements {
+            self.execute(statement)?;
+        }
+
+        Ok(())
+    }
+
+    fn execute(&mut self, stmt: &Stmt) -> Result<Flow> {
+        match stmt {
+            Stmt::Expression(expr) => {
+                self.evaluate(expr)?;
+            }
+            Stmt::Print { value, .. } => {
+                let value = self.evaluate(value)?;
+
+                println!("{value}");
+            }
+            Stmt::Var { name, initializer } => {
+                let value = match initializer {
+                    Some(initializer) => self.evaluate(initializer)?,
+                    None => Value::Nil,
+                };
+
+                self.environment
+                    .borrow_mut()
+                    .define(name.identifier(), value);
+            }
+            Stmt::Block(statements) => {
+                let environment = Environment::with_enclosing(self.environment.clone());
+
+                return self.execute_block(statements, Rc::new(RefCell::new(environment)));
+            }
+            Stmt::If { .. } => {
+                let mut branch = Some(stmt);
+
+                while let Some(Stmt::If {
+                    condition,
+                    then_branch,
+                    else_branch,
+                }) = branch
+                {
+                    if self.evaluate(condition)?.is_truthy() {
+                        return self.execute(then_branch);
+                    }
+
+                    branch = else_branch.as_deref();
+                }
+
+                if let Some(else_branch) = branch {
+                    return self.execute(else_branch);
+                }
+            }
+            Stmt::While {
+                label,
+                condition,
+                body,
+                increment,
+            } => {
+                let targets = |target: &Option<String>| {
+                    target.is_none() || target.as_deref() == label.as_ref().map(Token::identifier)
+                };
+
+                while self.evaluate(condition)?.is_truthy() {
+                    match self.execute(body)? {
+                        Flow::Normal => {}
+                        Flow::Break(target) if targets(&target) => break,
+                        Flow::Continue(target) if targets(&target) => {}
+                        flow => return Ok(flow),
+                    }
+
+                    if let Some(increment) = increment {
+                        self.evaluate(increment)?;
+                    }
+                }
+            }
+            Stmt::Break { label, .. } => {
+                return Ok(Flow::Break(
+                    label.as_ref().map(|label| label.identifier().into()),
+                ));
+            }
+            Stmt::Continue { label, .. } => {
+                return Ok(Flow::Continue(
+                    label.as_ref().map(|label| label.identifier().into()),
+                ));
+            }
+            Stmt::Function(declaration) => {
+                let function = Function::new(declaration.clone(), self.environment.clone(), false);
+
+                self.environment.borrow_mut().define(
+                    declaration.name.identifier(),
+                    Value::Callable(Rc::new(function)),
+                );
+            }
+            Stmt::Class {
+                name,
+                superclass,
+                methods,
+            } => {
+                let superclass = match superclass {
+                    Some(superclass) => match self.evaluate(superclass)? {
+                        Value::Class(class) => Some(class),
+                        _ => {
+                            let name = match superclass.as_ref() {
+                                Expr::Variable { name, .. } => name.clone(),
+                                _ => name.clone(),
+                            };
+
+                            return Err(Error::Interpreter(
+                                InterpreterError::SuperclassMustBeClass { name },
+                            ));
+                        }
+                    },
+                    None => None,
+                };
+
+                let enclosing = self.environment.clone();
+
+                if let Some(superclass) = &superclass {
+                    let mut environment = Environment::with_enclosing(enclosing.clone());
+
+                    environment.define("super", Value::Class(superclass.clone()));
+
+                    self.environment = Rc::new(RefCell::new(environment));
+                }
+
+                let methods = methods
+                    .iter()
+                    .map(|method| {
+                        let name = method.name.identifier();
+                        let function =
+                            Function::new(method.clone(), self.environment.clone(), name == "init");
+
+                        (name.to_string(), Rc::new(function))
+                    })
+                    .collect::<HashMap<_, _>>();
+
+                self.environment = enclosing;
+
+                let class = Class::new(name.identifier().into(), superclass, methods);
+
+                self.environment
+                    .borrow_mut()
+                    .define(name.identifier(), Value::Class(Rc::new(class)));
+            }
+            Stmt::Return { value, .. } => {
+                let value = match value {
+                    Some(value) => self.evaluate(value)?,
+                    None => Value::Nil,
+                };
+
+                return Ok(Flow::Return(value));
+            }
+            Stmt::Match {
+                scrutinee, arms, ..
+            } => {
+                let value = self.evaluate(scrutinee)?;
+
+                for arm in arms {
+                    let environment = Rc::new(RefCell::new(Environment::with_enclosing(
+                        self.environment.clone(),
+                    )));
+
+                    let previous = std::mem::replace(&mut self.environment, environment.clone());
+                    let matched = self.match_arm(arm, &value);
+                    self.environment = previous;
+
+                    if matched? {
+                        return self.execute_block(std::slice::from_ref(&arm.body), environment);
+                    }
+                }
+            }
+            Stmt::Error(_) => {}
+        }
+
+        Ok(Flow::Normal)
+    }
+
+    fn match_arm(&mut self, arm: &MatchArm, value: &Value) -> Result<bool> {
+        let binding = match &arm.pattern {
+            Pattern::Wildcard => None,
+            Pattern::Literal { value: literal, .. } => {
+                if !value.is_equal(&literal.into()) {
+                    return Ok(false);
+                }
+
+                None
+            }
+            Pattern::Range {
+                start,
+                end,
+                inclusive,
+                ..
+            } => {
+                let Value::Number(num) = value else {
+                    return Ok(false);
+                };
+
+                let below_end = if *inclusive { num <= end } else { num < end };
+
+                if !(start <= num && below_end) {
+                    return Ok(false);
+                }
+
+                None
+            }
+            Pattern::Binding(name) => Some(name),
+            Pattern::Class { name, class, depth } => {
+                let Value::Class(class_value) = self.look_up_variable(class, depth)? else {
+                    return Err(Error::Interpreter(InterpreterError::PatternMustBeClass {
+                        name: class.clone(),
+                    }));
+                };
+
+                let Value::Instance(instance) = value else {
+                    return Ok(false);
+                };
+
+                if !instance.borrow().is_instance_of(&class_value) {
+                    return Ok(false);
+                }
+
+                name.as_ref()
+            }
+        };
+
+        if let Some(name) = binding {
+            self.environment
+                .borrow_mut()
+                .define(name.identifier(), value.clone());
+        }
+
+        match &arm.guard {
+            Some(guard) => Ok(self.evaluate(guard)?.is_truthy()),
+            None => Ok(true),
+        }
+    }
+
+    pub fn execute_block(
+        &mut self,
+        statements: &[Stmt],
+        environment: Rc<RefCell<Environment>>,
+    ) -> Result<Flow> {
+        let previous = std::mem::replace(&mut self.environment, environment);
+
+        let mut result = Ok(Flow::Normal);
+
+        for statement in statements {
+            result = self.execute(statement);
+
+            if !matches!(result, Ok(Flow::Normal)) {
+                break;
+            }
+        }
+
+        self.environment = previous;
+
+        result
+    }
+
+    fn evaluate_in(&mut self, expr: &Expr, environment: Rc<RefCell<Environment>>) -> Result<Value> {
+        let previous = std::mem::replace(&mut self.environment, environment);
+
+        let result = self.evaluate(expr);
+
+        self.environment = previous;
+
+        result
+    }
+
+    fn evaluate(&mut self, expr: &Expr) -> Result<Value> {
+        match expr {
+            Expr::Literal { value, .. } => Ok(value.into()),
+            Expr::Error(_) => Ok(Value::Nil),
+            Expr::Grouping(expr) => self.evaluate(expr),
+            Expr::Interpolation(parts) => {
+                let mut buf = String::new();
+
+                for part in parts {
+                    buf += &self.evaluate(part)?.to_string();
+                }
+
+                Ok(Value::String(buf))
+            }
+            Expr::Unary { operator, right } => {
+                let right = self.evaluate(right)?;
+
+                self.unary(operator, right)
+            }
+            Expr::Binary { .. }
+            | Expr::Logical { .. }
+            | Expr::Coalesce { .. }
+            | Expr::Call { .. }
+            | Expr::Get { .. }
+            | Expr::OptionalGet { .. } => {
+                let (operand, links) = expr.unchain();
+                let mut value = self.evaluate(operand)?;
+
+                for link in links {
+                    value = self.link(link, value)?;
+                }
+
+                Ok(value)
+            }
+            Expr::Variable { name, depth } => self.look_up_variable(name, depth),
+            Expr::Assign { name, value, depth } => {
+                let value = self.evaluate(value)?;
+
+                self.assign_variable(name, depth, value)
+            }
+            Expr::CompoundAssign {
+                name,
+                operator,
+                value,
+                depth,
+            } => {
+                let current = self.look_up_variable(name, depth)?;
+                let value = self.evaluate(value)?;
+                let value = self.binary(current, operator, value)?;
+
+                self.assign_variable(name, depth, value)
+            }
+            Expr::Comma(exprs) => {
+                let mut value = Value::Nil;
+
+                for expr in exprs {
+                    value = self.evaluate(expr)?;
+                }
+
+                Ok(value)
+            }
+            Expr::Conditional {
+                condition,
+                then_branch,
+                else_branch,
+            } => {
+                if self.evaluate(condition)?.is_truthy() {
+                    self.evaluate(then_branch)
+                } else {
+                    self.evaluate(else_branch)
+                }
+            }
+            Expr::Chain(expr) => Ok(self.chain(expr)?.unwrap_or(Value::Nil)),
+            Expr::Lambda(declaration) => Ok(Value::Callable(Rc::new(Function::new(
+                declaration.clone(),
+                self.environment.clone(),
+                false,
+            )))),
+            Expr::Set {
+                object,
+                name,
+                value,
+            } => {
+                let Value::Instance(instance) = self.evaluate(object)? else {
+                    return Err(Error::Interpreter(
+                        InterpreterError::OnlyInstancesHaveProperties { name: name.clone() },
+                    ));
+                };
+
+                let value = self.evaluate(value)?;
+
+                instance.borrow_mut().set(name, value.clone());
+
+                Ok(value)
+            }
+            Expr::CompoundSet {
+                object,
+                name,
+                operator,
+                value,
+            } => {
+                let Value::Instance(instance) = self.evaluate(object)? else {
+                    return Err(Error::Interpreter(
+                        InterpreterError::OnlyInstancesHaveProperties { name: name.clone() },
+                    ));
+                };
+
+                let current = Instance::get(&instance, name)?;
+                let value = self.evaluate(value)?;
+                let value = self.binary(current, operator, value)?;
+
+                instance.borrow_mut().set(name, value.clone());
+
+                Ok(value)
+            }
+            Expr::This { keyword, depth } => self.look_up_variable(keyword, depth),
+            Expr::Super {
+                keyword,
+                method,
+                depth,
+            } => {
+                let distance = depth.get().unwrap_or(0);
+
+                let Value::Class(superclass) =
+                    self.environment.borrow().get_at(distance, keyword)?
+                else {
+                    return Err(Error::Interpreter(
+                        InterpreterError::SuperclassMustBeClass {
+                            name: keyword.clone(),
+                        },
+                    ));
+                };
+
+                let object = self
+                    .environment
+                    .borrow()
+                    .get_local_at(distance.saturating_sub(1), "this")
+                    .unwrap_or(Value::Nil);
+
+                match superclass.find_method(method.identifier()) {
+                    Some(method) => Ok(Value::Callable(Rc::new(method.bind(object)))),
+                    None => Err(Error::Interpreter(InterpreterError::UndefinedProperty {
+                        name: method.clone(),
+                    })),
+                }
+            }
+        }
+    }
+
+    fn look_up_variable(&self, name: &Token, depth: &Cell<Option<usize>>) -> Result<Value> {
+        match depth.get() {
+            Some(distance) => self.environment.borrow().get_at(distance, name),
+            None => self.globals.borrow().get(name),
+        }
+    }
+
+    fn chain(&mut self, expr: &Expr) -> Result<Option<Value>> {
+        let (operand, links) = expr.unchain();
+        let mut value = self.evaluate(operand)?;
+
+        for link in links {
+            if matches!((link, &value), (Expr::OptionalGet { .. }, Value::Nil)) {
+                return Ok(None);
+            }
+
+            value = self.link(link, value)?;
+        }
+
+        Ok(Some(value))
+    }
+
+    fn link(&mut self, link: &Expr, left: Value) -> Result<Value> {
+        match link {
+            Expr::Binary {
+                operator, right, ..
+            } => {
+                let right = self.evaluate(right)?;
+
+                self.binary(left, operator, right)
+            }
+            Expr::Logical {
+                operator, right, ..
+            } => {
+                let short_circuits = match operator.token_type() {
+                    TokenType::Or => left.is_truthy(),
+                    _ => !left.is_truthy(),
+                };
+
+                if short_circuits {
+                    Ok(left)
+                } else {
+                    self.evaluate(right)
+                }
+            }
+            Expr::Coalesce { right, .. } => match left {
+                Value::Nil => self.evaluate(right),
+                left => Ok(left),
+            },
+            Expr::Call {
+                paren, arguments, ..
+            } => self.call(left, paren, arguments),
+            Expr::Get { name, .. } => Self::get(left, name),
+            Expr::OptionalGet { name, .. } => match left {
+                Value::Nil => Ok(Value::Nil),
+                object => Self::get(object, name),
+            },
+            _ => unreachable!("unchain only yields chain links"),
+        }
+    }
+
+    fn call(&mut self, callee: Value, paren: &Token, arguments: &[Argument]) -> Result<Value> {
+        let mut positional = Vec::new();
+        let mut named = Vec::new();
+
+        for argument in arguments {
+            match argument {
+                Argument::Positional(value) => positional.push(self.evaluate(value)?),
+                Argument::Spread { ellipsis, value } => match self.evaluate(value)? {
+                    Value::List(list) => positional.extend(list.iter().cloned()),
+                    _ => {
+                        return Err(Error::Interpreter(InterpreterError::SpreadMustBeList {
+                            ellipsis: ellipsis.clone(),
+                        }));
+                    }
+                },
+                Argument::Named { name, value } => {
+                    named.push((name.clone(), self.evaluate(value)?))
+                }
+            }
+        }
+
+        let callable: Rc<dyn Callable> = match callee {
+            Value::Callable(callable) => callable,
+            Value::Class(class) => Rc::new(class),
+            _ => {
+                return Err(Error::Interpreter(InterpreterError::NotCallable {
+                    paren: paren.clone(),
+                }));
+            }
+        };
+
+        if self.call_depth == MAX_CALL_DEPTH {
+            return Err(Error::Interpreter(InterpreterError::StackOverflow {
+                paren: paren.clone(),
+            }));
+        }
+
+        self.call_depth += 1;
+
+        let result = callable.call(self, paren, Arguments { positional, named });
+
+        self.call_depth -= 1;
+
+        result
+    }
+
+    fn get(object: Value, name: &Token) -> Result<Value> {
+        match object {
+            Value::Instance(instance) => Instance::get(&instance, name),
+            _ => Err(Error::Interpreter(
+                InterpreterError::OnlyInstancesHaveProperties { name: name.clone() },
+            )),
+        }
+    }
+
+    fn assign_variable(
+        &mut self,
+        name: &Token,
+        depth: &Cell<Option<usize>>,
+        value: Value,
+    ) -> Result<Value> {
+        match depth.get() {
+            Some(distance) => {
+                self.environment
+                    .borrow_mut()
+                    .assign_at(distance, name, value.clone())?;
+            }
+            None => self.globals.borrow_mut().assign(name, value.clone())?,
+        }
+
+        Ok(value)
+    }
+
+    fn unary(&mut self, operator: &Token, right: Value) -> Result<Value> {
+        match operator.token_type() {
+            TokenType::Minus => match right {
+                Value::Number(num) => Ok(Value::Number(-num)),
+                _ => Err(Error::Interpreter(InterpreterError::OperandMustBeNumber {
+                    operator: operator.clone(),
+                })),
+            },
+            TokenType::Bang => Ok(Value::Bool(!right.is_truthy())),
+            TokenType::Tilde => match right {
+                Value::Number(num) => num
+                    .bit_not()
+                    .map(Value::Number)
+                    .map_err(|error| arithmetic_error(operator, error)),
+                _ => Err(Error::Interpreter(InterpreterError::OperandMustBeNumber {
+                    operator: operator.clone(),
+                })),
+            },
+            _ => Err(Error::Interpreter(InterpreterError::InvalidOperator {
+                operator: operator.clone(),
+            })),
+        }
+    }
+
+    fn binary(&mut self, left: Value, operator: &Token, right: Value) -> Result<Value> {
+        match operator.token_type() {
+            TokenType::EqualEqual => return Ok(Value::Bool(left.is_equal(&right))),
+            TokenType::BangEqual => return Ok(Value::Bool(!left.is_equal(&right))),
+            TokenType::Plus => {
+                return match (left, right) {
+                    (Value::Number(a), Value::Number(b)) => a
+                        .checked_add(b)
+                        .map(Value::Number)
+                        .map_err(|error| arithmetic_error(operator, error)),
+                    (Value::String(a), Value::String(b)) => Ok(Value::String(a + &b)),
+                    _ => Err(Error::Interpreter(
+                        InterpreterError::OperandsMustBeNumbersOrStrings {
+                            operator: operator.clone(),
+                        },
+                    )),
+                };
+            }
+            _ => {}
+        }
+
+        let (Value::Number(a), Value::Number(b)) = (left, right) else {
+            return Err(Error::Interpreter(
+                InterpreterError::OperandsMustBeNumbers {
+                    operator: operator.clone(),
+                },
+            ));
+        };
+
+        let arithmetic: fn(Number, Number) -> std::result::Result<Number, ArithmeticError> =
+            match operator.token_type() {
+                TokenType::Minus => Number::checked_sub,
+                TokenType::Star => Number::checked_mul,
+                TokenType::Slash => Number::checked_div,
+                TokenType::Percent => Number::checked_rem,
+                TokenType::StarStar => Number::checked_pow,
+                TokenType::Ampersand => Number::bit_and,
+                TokenType::Pipe => Number::bit_or,
+                TokenType::Caret => Number::bit_xor,
+                TokenType::LessLess => Number::shl,
+                TokenType::GreaterGreater => Number::shr,
+                TokenType::Greater => return Ok(Value::Bool(a > b)),
+                TokenType::GreaterEqual => return Ok(Value::Bool(a >= b)),
+                TokenType::Less => return Ok(Value::Bool(a < b)),
+                TokenType::LessEqual => return Ok(Value::Bool(a <= b)),
+                _ => {
+                    return Err(Error::Interpreter(InterpreterError::InvalidOperator {
+                        operator: operator.clone(),
+                    }));
+                }
+            };
+
+        arithmetic(a, b)
+            .map(Value::Number)
+            .map_err(|error| arithmetic_error(operator, error))
+    }
+}
+
+fn arithmetic_error(operator: &Token, error: ArithmeticError) -> Error {
+    Error::Interpreter(InterpreterError::Arithmetic {
+        operator: operator.clone(),
+        error,
+    })
+}
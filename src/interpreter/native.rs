@@ -0,0 +1,123 @@
+use std::{
+    fmt::{Debug, Display},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    error::{Error, InterpreterError, NativeError},
+    number::Number,
+    result::Result,
+    scanner::token::Token,
+};
+
+use super::{Arguments, Callable, Interpreter, Value};
+
+pub struct NativeFunction {
+    name: &'static str,
+    arity: usize,
+    function: NativeFn,
+}
+
+type NativeFn = fn(&[Value]) -> std::result::Result<Value, NativeError>;
+
+impl NativeFunction {
+    pub fn new(name: &'static str, arity: usize, function: NativeFn) -> Self {
+        Self {
+            name,
+            arity,
+            function,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl Callable for NativeFunction {
+    fn call(
+        &self,
+        _interpreter: &mut Interpreter,
+        paren: &Token,
+        arguments: Arguments,
+    ) -> Result<Value> {
+        if let Some((name, _)) = arguments.named.first() {
+            return Err(Error::Interpreter(InterpreterError::UnknownParameter {
+                name: name.clone(),
+            }));
+        }
+
+        if arguments.positional.len() != self.arity {
+            return Err(Error::Interpreter(InterpreterError::ArityMismatch {
+                paren: paren.clone(),
+                expected: self.arity,
+                got: arguments.positional.len(),
+            }));
+        }
+
+        (self.function)(&arguments.positional).map_err(|error| {
+            Error::Interpreter(InterpreterError::Native {
+                paren: paren.clone(),
+                error,
+            })
+        })
+    }
+}
+
+impl Debug for NativeFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "NativeFunction({})", self.name)
+    }
+}
+
+impl Display for NativeFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<native fn>")
+    }
+}
+
+pub fn natives() -> Vec<NativeFunction> {
+    vec![
+        NativeFunction::new("clock", 0, clock),
+        NativeFunction::new("len", 1, len),
+        NativeFunction::new("get", 2, get),
+    ]
+}
+
+fn clock(_arguments: &[Value]) -> std::result::Result<Value, NativeError> {
+    let elapsed = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+
+    Ok(Value::Number(Number::Float(elapsed.as_secs_f64())))
+}
+
+fn len(arguments: &[Value]) -> std::result::Result<Value, NativeError> {
+    let Value::List(list) = &arguments[0] else {
+        return Err(NativeError::ExpectedList { native: "len" });
+    };
+
+    Ok(Value::Number(Number::Integer(list.len() as i64)))
+}
+
+fn get(arguments: &[Value]) -> std::result::Result<Value, NativeError> {
+    let Value::List(list) = &arguments[0] else {
+        return Err(NativeError::ExpectedList { native: "get" });
+    };
+
+    let Value::Number(index @ (Number::Integer(_) | Number::Big(_))) = &arguments[1] else {
+        return Err(NativeError::IndexNotInteger);
+    };
+
+    let element = match index {
+        Number::Integer(i) => usize::try_from(*i).ok().and_then(|i| list.get(i)),
+        _ => None,
+    };
+
+    element
+        .cloned()
+        .ok_or_else(|| NativeError::IndexOutOfRange {
+            index: index.clone(),
+            len: list.len(),
+        })
+}
@@ -0,0 +1,75 @@
+use std::{cell::RefCell, fmt::Display, rc::Rc};
+
+use crate::{ast::Literal, number::Number};
+
+use super::{Callable, Class, Instance};
+
+#[derive(Debug, Clone)]
+pub enum Value {
+    Number(Number),
+    String(String),
+    Bool(bool),
+    Nil,
+    Callable(Rc<dyn Callable>),
+    Class(Rc<Class>),
+    Instance(Rc<RefCell<Instance>>),
+    List(Rc<Vec<Value>>),
+}
+
+impl Value {
+    pub fn is_truthy(&self) -> bool {
+        !matches!(self, Self::Nil | Self::Bool(false))
+    }
+
+    pub fn is_equal(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Number(a), Self::Number(b)) => a == b,
+            (Self::String(a), Self::String(b)) => a == b,
+            (Self::Bool(a), Self::Bool(b)) => a == b,
+            (Self::Nil, Self::Nil) => true,
+            (Self::Callable(a), Self::Callable(b)) => Rc::ptr_eq(a, b),
+            (Self::Class(a), Self::Class(b)) => Rc::ptr_eq(a, b),
+            (Self::Instance(a), Self::Instance(b)) => Rc::ptr_eq(a, b),
+            (Self::List(a), Self::List(b)) => Rc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+impl From<&Literal> for Value {
+    fn from(value: &Literal) -> Self {
+        match value {
+            Literal::Number(num) => Self::Number(num.clone()),
+            Literal::String(s) => Self::String(s.clone()),
+            Literal::Bool(val) => Self::Bool(*val),
+            Literal::Nil => Self::Nil,
+        }
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Number(num) => write!(f, "{num}"),
+            Self::String(s) => write!(f, "{s}"),
+            Self::Bool(val) => write!(f, "{val}"),
+            Self::Nil => write!(f, "nil"),
+            Self::Callable(callable) => write!(f, "{callable}"),
+            Self::Class(class) => write!(f, "{class}"),
+            Self::Instance(instance) => write!(f, "{}", instance.borrow()),
+            Self::List(list) => {
+                write!(f, "[")?;
+
+                for (i, value) in list.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+
+                    write!(f, "{value}")?;
+                }
+
+                write!(f, "]")
+            }
+        }
+    }
+}
@@ -1,43 +1,134 @@
 mod ast;
+mod chunk;
+mod compiler;
+mod diagnostic;
 mod error;
+mod interpreter;
+mod number;
 mod parser;
+mod resolver;
 mod result;
 mod scanner;
+mod vm;
 
 use std::{
-    env::args,
+    env::{args, var_os},
     fs,
-    io::{self, Write, stdin},
+    io::{self, IsTerminal, Write, stdin},
+    panic,
+    process::ExitCode,
+    thread,
 };
 
-use parser::Parser;
-use result::Result;
+use compiler::Compiler;
+use diagnostic::Diagnostic;
+use error::Error;
+use interpreter::Interpreter;
+use parser::{DEFAULT_MAX_DEPTH, Parser};
+use resolver::Resolver;
 use scanner::Scanner;
+use vm::Vm;
 
-fn run(source: String) -> Result<()> {
-    let mut scanner = Scanner::new(source.as_str());
+const EX_USAGE: u8 = 64;
+const EX_DATAERR: u8 = 65;
+const EX_SOFTWARE: u8 = 70;
 
-    scanner.scan_tokens();
+const STACK_SIZE: usize = 192 * 1024 * 1024;
+const STACK_PER_DEPTH: usize = 64 * 1024;
+const MAX_DEPTH_LIMIT: usize = 16 * 1024;
 
-    let mut parser = Parser::new(scanner.tokens());
+const USAGE: &str = "Usage: rracone [--backend tree|vm] [--disassemble] [--max-depth n] [script]";
 
-    match parser.parse() {
-        Ok(expression) => {
-            dbg!(expression);
+enum Backend {
+    Interpreter(Interpreter),
+    Vm { vm: Vm, disassemble: bool },
+}
+
+fn report(name: &str, source: &str, diagnostic: impl Into<Diagnostic>) {
+    let color = io::stderr().is_terminal() && var_os("NO_COLOR").is_none();
+
+    eprint!("{}", diagnostic.into().render(name, source, color));
+}
+
+fn run(backend: &mut Backend, max_depth: usize, name: &str, source: &str) -> ExitCode {
+    let report_all = |errors: &[Error]| {
+        for error in errors {
+            report(name, source, error);
         }
+    };
+
+    let mut scanner = Scanner::new(source);
+
+    let errors = scanner.scan_tokens();
+
+    if !errors.is_empty() {
+        report_all(&errors);
+
+        return ExitCode::from(EX_DATAERR);
+    }
+
+    let mut parser = Parser::new(scanner.tokens()).with_max_depth(max_depth);
+
+    let errors = parser.parse();
+
+    if !errors.is_empty() {
+        report_all(&errors);
+
+        return ExitCode::from(EX_DATAERR);
+    }
+
+    let statements = parser.statements();
+
+    let mut resolver = Resolver::new();
+    let errors = resolver.resolve(statements);
+
+    for warning in resolver.warnings() {
+        report(name, source, warning);
+    }
+
+    if !errors.is_empty() {
+        report_all(&errors);
+
+        return ExitCode::from(EX_DATAERR);
+    }
+
+    let result = match backend {
+        Backend::Interpreter(interpreter) => interpreter.interpret(statements),
+        Backend::Vm { vm, disassemble } => {
+            let function = match Compiler::new().compile(statements) {
+                Ok(function) => function,
+                Err(e) => {
+                    report_all(&[e]);
+
+                    return ExitCode::from(EX_DATAERR);
+                }
+            };
+
+            if *disassemble {
+                print!("{}", function.chunk.disassemble(&function.to_string()));
+            }
+
+            vm.interpret(function)
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
         Err(e) => {
-            dbg!(e);
+            report_all(&[e]);
+
+            ExitCode::from(EX_SOFTWARE)
         }
     }
-
-    Ok(())
 }
 
-fn run_file(path: String) -> Result<()> {
-    run(fs::read_to_string(path)?)
+fn run_file(mut backend: Backend, max_depth: usize, path: String) -> io::Result<ExitCode> {
+    let source = fs::read_to_string(&path)?;
+
+    Ok(run(&mut backend, max_depth, &path, &source))
 }
 
-fn run_prompt() -> Result<()> {
+fn run_prompt(mut backend: Backend, max_depth: usize) -> io::Result<ExitCode> {
     loop {
         let mut input = String::new();
 
@@ -52,22 +143,78 @@ fn run_prompt() -> Result<()> {
             break;
         }
 
-        if let Err(e) = run(input) {
-            println!("{e}")
+        run(&mut backend, max_depth, "<repl>", &input);
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn main() -> io::Result<ExitCode> {
+    let mut backend = None;
+    let mut disassemble = false;
+    let mut max_depth = DEFAULT_MAX_DEPTH;
+    let mut script = None;
+
+    let mut args = args().skip(1);
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--backend" => backend = args.next(),
+            "--disassemble" => disassemble = true,
+            "--max-depth" => match args
+                .next()
+                .and_then(|depth| depth.parse().ok())
+                .filter(|depth| *depth <= MAX_DEPTH_LIMIT)
+            {
+                Some(depth) => max_depth = depth,
+                None => {
+                    println!("{USAGE}");
+                    return Ok(ExitCode::from(EX_USAGE));
+                }
+            },
+            _ if arg.starts_with("--") || script.is_some() => {
+                println!("{USAGE}");
+                return Ok(ExitCode::from(EX_USAGE));
+            }
+            _ => script = Some(arg),
         }
     }
 
-    Ok(())
+    let tree = match (backend.as_deref(), disassemble) {
+        (None | Some("tree"), false) => true,
+        (None | Some("vm"), _) => false,
+        _ => {
+            println!("{USAGE}");
+            return Ok(ExitCode::from(EX_USAGE));
+        }
+    };
+
+    let stack_size = STACK_SIZE + max_depth * STACK_PER_DEPTH;
+
+    thread::Builder::new()
+        .stack_size(stack_size)
+        .spawn(move || start(tree, disassemble, max_depth, script))?
+        .join()
+        .unwrap_or_else(|panic| panic::resume_unwind(panic))
 }
 
-fn main() -> Result<()> {
-    if args().len() > 2 {
-        println!("Usage: rracone [script]");
-    } else if args().len() == 2 {
-        return run_file(args().nth(1).unwrap());
+fn start(
+    tree: bool,
+    disassemble: bool,
+    max_depth: usize,
+    script: Option<String>,
+) -> io::Result<ExitCode> {
+    let backend = if tree {
+        Backend::Interpreter(Interpreter::new())
     } else {
-        return run_prompt();
-    }
+        Backend::Vm {
+            vm: Vm::new(),
+            disassemble,
+        }
+    };
 
-    Ok(())
+    match script {
+        Some(path) => run_file(backend, max_depth, path),
+        None => run_prompt(backend, max_depth),
+    }
 }
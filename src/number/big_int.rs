@@ -0,0 +1,648 @@
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    ops::{Add, BitAnd, BitOr, BitXor, Mul, Neg, Not, Sub},
+};
+
+const BASE_BITS: u32 = 32;
+const DECIMAL_CHUNK: u32 = 1_000_000_000;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    negative: bool,
+    magnitude: Vec<u32>,
+}
+
+impl BigInt {
+    pub fn from_str_radix(digits: &str, radix: u32) -> Option<Self> {
+        let mut magnitude = Vec::new();
+
+        for c in digits.chars() {
+            mul_add_small(&mut magnitude, radix, c.to_digit(radix)?);
+        }
+
+        Some(Self::new(false, magnitude))
+    }
+
+    pub fn from_f64(num: f64) -> Option<Self> {
+        if !num.is_finite() || num.fract() != 0.0 {
+            return None;
+        }
+
+        let bits = num.to_bits();
+        let exponent = ((bits >> 52) & 0x7ff) as i32;
+        let fraction = bits & ((1 << 52) - 1);
+
+        if exponent == 0 {
+            return Some(Self::from(0));
+        }
+
+        let mantissa = fraction | (1 << 52);
+        let shift = exponent - 1075;
+
+        let magnitude = if shift >= 0 {
+            shift_left(&from_u64(mantissa), shift as usize)
+        } else {
+            from_u64(mantissa >> -shift)
+        };
+
+        Some(Self::new(num < 0.0, magnitude))
+    }
+
+    pub fn to_i64(&self) -> Option<i64> {
+        if self.magnitude.len() > 2 {
+            return None;
+        }
+
+        let magnitude = self
+            .magnitude
+            .iter()
+            .rev()
+            .fold(0u64, |acc, &limb| (acc << BASE_BITS) | limb as u64);
+
+        if self.negative {
+            0i64.checked_sub_unsigned(magnitude)
+        } else {
+            i64::try_from(magnitude).ok()
+        }
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        let magnitude = self.magnitude.iter().rev().fold(0.0, |acc, &limb| {
+            acc * 2f64.powi(BASE_BITS as i32) + limb as f64
+        });
+
+        if self.negative { -magnitude } else { magnitude }
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn bits(&self) -> u64 {
+        match self.magnitude.last() {
+            Some(last) => {
+                self.magnitude.len() as u64 * BASE_BITS as u64 - last.leading_zeros() as u64
+            }
+            None => 0,
+        }
+    }
+
+    pub fn pow(&self, mut exponent: u64) -> Self {
+        let mut base = self.clone();
+        let mut result = Self::from(1);
+
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = &result * &base;
+            }
+
+            exponent >>= 1;
+
+            if exponent > 0 {
+                base = &base * &base;
+            }
+        }
+
+        result
+    }
+
+    pub fn shl(&self, shift: usize) -> Self {
+        Self::new(self.negative, shift_left(&self.magnitude, shift))
+    }
+
+    pub fn shr(&self, shift: usize) -> Self {
+        let (magnitude, truncated) = shift_right(&self.magnitude, shift);
+
+        if self.negative && truncated {
+            Self::new(true, add_magnitude(&magnitude, &[1]))
+        } else {
+            Self::new(self.negative, magnitude)
+        }
+    }
+
+    pub fn div_rem(&self, other: &Self) -> Option<(Self, Self)> {
+        if other.magnitude.is_empty() {
+            return None;
+        }
+
+        let (quotient, remainder) = div_rem_magnitude(&self.magnitude, &other.magnitude);
+
+        Some((
+            Self::new(self.negative != other.negative, quotient),
+            Self::new(self.negative, remainder),
+        ))
+    }
+
+    fn bitwise(&self, other: &Self, op: fn(u32, u32) -> u32) -> Self {
+        let len = self.magnitude.len().max(other.magnitude.len());
+
+        let a = self.twos_complement(len);
+        let b = other.twos_complement(len);
+
+        let limbs = a.iter().zip(&b).map(|(&a, &b)| op(a, b)).collect();
+        let negative = op(self.sign_limb(), other.sign_limb()) != 0;
+
+        Self::from_twos_complement(negative, limbs)
+    }
+
+    fn sign_limb(&self) -> u32 {
+        if self.negative { u32::MAX } else { 0 }
+    }
+
+    fn twos_complement(&self, len: usize) -> Vec<u32> {
+        if !self.negative {
+            let mut limbs = self.magnitude.clone();
+            limbs.resize(len, 0);
+
+            return limbs;
+        }
+
+        let mut limbs = sub_magnitude(&self.magnitude, &[1]);
+        limbs.resize(len, 0);
+
+        limbs.iter().map(|limb| !limb).collect()
+    }
+
+    fn from_twos_complement(negative: bool, limbs: Vec<u32>) -> Self {
+        if !negative {
+            return Self::new(false, limbs);
+        }
+
+        let inverted: Vec<u32> = limbs.iter().map(|limb| !limb).collect();
+
+        Self::new(true, add_magnitude(&inverted, &[1]))
+    }
+
+    fn new(negative: bool, mut magnitude: Vec<u32>) -> Self {
+        while magnitude.last() == Some(&0) {
+            magnitude.pop();
+        }
+
+        Self {
+            negative: negative && !magnitude.is_empty(),
+            magnitude,
+        }
+    }
+}
+
+impl From<i64> for BigInt {
+    fn from(value: i64) -> Self {
+        Self::new(value < 0, from_u64(value.unsigned_abs()))
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> Self::Output {
+        BigInt::new(!self.negative, self.magnitude.clone())
+    }
+}
+
+impl Add for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, other: Self) -> Self::Output {
+        if self.negative == other.negative {
+            return BigInt::new(
+                self.negative,
+                add_magnitude(&self.magnitude, &other.magnitude),
+            );
+        }
+
+        match compare_magnitude(&self.magnitude, &other.magnitude) {
+            Ordering::Less => BigInt::new(
+                other.negative,
+                sub_magnitude(&other.magnitude, &self.magnitude),
+            ),
+            _ => BigInt::new(
+                self.negative,
+                sub_magnitude(&self.magnitude, &other.magnitude),
+            ),
+        }
+    }
+}
+
+impl Sub for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, other: Self) -> Self::Output {
+        self + &-other
+    }
+}
+
+impl Mul for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, other: Self) -> Self::Output {
+        BigInt::new(
+            self.negative != other.negative,
+            mul_magnitude(&self.magnitude, &other.magnitude),
+        )
+    }
+}
+
+impl Not for &BigInt {
+    type Output = BigInt;
+
+    fn not(self) -> Self::Output {
+        &-self - &BigInt::from(1)
+    }
+}
+
+impl BitAnd for &BigInt {
+    type Output = BigInt;
+
+    fn bitand(self, other: Self) -> Self::Output {
+        self.bitwise(other, |a, b| a & b)
+    }
+}
+
+impl BitOr for &BigInt {
+    type Output = BigInt;
+
+    fn bitor(self, other: Self) -> Self::Output {
+        self.bitwise(other, |a, b| a | b)
+    }
+}
+
+impl BitXor for &BigInt {
+    type Output = BigInt;
+
+    fn bitxor(self, other: Self) -> Self::Output {
+        self.bitwise(other, |a, b| a ^ b)
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => compare_magnitude(&self.magnitude, &other.magnitude),
+            (true, true) => compare_magnitude(&other.magnitude, &self.magnitude),
+        }
+    }
+}
+
+impl Display for BigInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.magnitude.is_empty() {
+            return write!(f, "0");
+        }
+
+        let mut magnitude = self.magnitude.clone();
+        let mut chunks = Vec::new();
+
+        while !magnitude.is_empty() {
+            chunks.push(div_rem_small(&mut magnitude, DECIMAL_CHUNK));
+        }
+
+        if self.negative {
+            write!(f, "-")?;
+        }
+
+        let mut chunks = chunks.iter().rev();
+
+        if let Some(first) = chunks.next() {
+            write!(f, "{first}")?;
+        }
+
+        for chunk in chunks {
+            write!(f, "{chunk:09}")?;
+        }
+
+        Ok(())
+    }
+}
+
+fn from_u64(value: u64) -> Vec<u32> {
+    let mut magnitude = vec![value as u32, (value >> BASE_BITS) as u32];
+
+    while magnitude.last() == Some(&0) {
+        magnitude.pop();
+    }
+
+    magnitude
+}
+
+fn compare_magnitude(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(a.len().max(b.len()) + 1);
+    let mut carry = 0u64;
+
+    for i in 0..a.len().max(b.len()) {
+        let sum = *a.get(i).unwrap_or(&0) as u64 + *b.get(i).unwrap_or(&0) as u64 + carry;
+
+        result.push(sum as u32);
+        carry = sum >> BASE_BITS;
+    }
+
+    if carry > 0 {
+        result.push(carry as u32);
+    }
+
+    result
+}
+
+fn sub_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(a.len());
+    let mut borrow = 0i64;
+
+    for (i, &limb) in a.iter().enumerate() {
+        let mut difference = limb as i64 - *b.get(i).unwrap_or(&0) as i64 - borrow;
+
+        borrow = if difference < 0 {
+            difference += 1 << BASE_BITS;
+            1
+        } else {
+            0
+        };
+
+        result.push(difference as u32);
+    }
+
+    result
+}
+
+fn mul_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = vec![0u32; a.len() + b.len()];
+
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+
+        for (j, &y) in b.iter().enumerate() {
+            let product = x as u64 * y as u64 + result[i + j] as u64 + carry;
+
+            result[i + j] = product as u32;
+            carry = product >> BASE_BITS;
+        }
+
+        result[i + b.len()] = carry as u32;
+    }
+
+    result
+}
+
+fn mul_add_small(magnitude: &mut Vec<u32>, factor: u32, addend: u32) {
+    let mut carry = addend as u64;
+
+    for limb in magnitude.iter_mut() {
+        let product = *limb as u64 * factor as u64 + carry;
+
+        *limb = product as u32;
+        carry = product >> BASE_BITS;
+    }
+
+    if carry > 0 {
+        magnitude.push(carry as u32);
+    }
+}
+
+fn div_rem_small(magnitude: &mut Vec<u32>, divisor: u32) -> u32 {
+    let mut remainder = 0u64;
+
+    for limb in magnitude.iter_mut().rev() {
+        let current = (remainder << BASE_BITS) | *limb as u64;
+
+        *limb = (current / divisor as u64) as u32;
+        remainder = current % divisor as u64;
+    }
+
+    while magnitude.last() == Some(&0) {
+        magnitude.pop();
+    }
+
+    remainder as u32
+}
+
+fn div_rem_magnitude(dividend: &[u32], divisor: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    if let [divisor] = divisor {
+        let mut quotient = dividend.to_vec();
+        let remainder = div_rem_small(&mut quotient, *divisor);
+
+        return (quotient, from_u64(remainder as u64));
+    }
+
+    let mut quotient = vec![0u32; dividend.len()];
+    let mut remainder = Vec::new();
+
+    for i in (0..dividend.len() * BASE_BITS as usize).rev() {
+        remainder = shift_left(&remainder, 1);
+
+        let bit = (dividend[i / BASE_BITS as usize] >> (i % BASE_BITS as usize)) & 1;
+
+        if bit == 1 {
+            match remainder.first_mut() {
+                Some(limb) => *limb |= 1,
+                None => remainder.push(1),
+            }
+        }
+
+        if compare_magnitude(&remainder, divisor) != Ordering::Less {
+            remainder = sub_magnitude(&remainder, divisor);
+
+            while remainder.last() == Some(&0) {
+                remainder.pop();
+            }
+
+            quotient[i / BASE_BITS as usize] |= 1 << (i % BASE_BITS as usize);
+        }
+    }
+
+    (quotient, remainder)
+}
+
+fn shift_left(magnitude: &[u32], shift: usize) -> Vec<u32> {
+    if magnitude.is_empty() {
+        return Vec::new();
+    }
+
+    let limbs = shift / BASE_BITS as usize;
+    let bits = (shift % BASE_BITS as usize) as u32;
+
+    let mut result = vec![0u32; limbs];
+    let mut carry = 0u32;
+
+    for &limb in magnitude {
+        if bits == 0 {
+            result.push(limb);
+        } else {
+            result.push((limb << bits) | carry);
+            carry = limb >> (BASE_BITS - bits);
+        }
+    }
+
+    if carry > 0 {
+        result.push(carry);
+    }
+
+    result
+}
+
+fn shift_right(magnitude: &[u32], shift: usize) -> (Vec<u32>, bool) {
+    let limbs = shift / BASE_BITS as usize;
+    let bits = (shift % BASE_BITS as usize) as u32;
+
+    if limbs >= magnitude.len() {
+        return (Vec::new(), !magnitude.is_empty());
+    }
+
+    let truncated = magnitude[..limbs].iter().any(|&limb| limb != 0)
+        || magnitude[limbs] & ((1 << bits) - 1) != 0;
+
+    let mut result = Vec::with_capacity(magnitude.len() - limbs);
+
+    for i in limbs..magnitude.len() {
+        let low = magnitude[i] >> bits;
+        let high = match magnitude.get(i + 1) {
+            Some(next) if bits > 0 => next << (BASE_BITS - bits),
+            _ => 0,
+        };
+
+        result.push(low | high);
+    }
+
+    (result, truncated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big(value: i128) -> BigInt {
+        let magnitude = BigInt::from_str_radix(&value.unsigned_abs().to_string(), 10).unwrap();
+
+        if value < 0 { -&magnitude } else { magnitude }
+    }
+
+    const VALUES: [i128; 12] = [
+        0,
+        1,
+        -1,
+        7,
+        -7,
+        (1 << 32) + 5,
+        -(1 << 32) - 5,
+        (1 << 64) + 3,
+        -(1 << 64),
+        (1 << 100) + 12_345,
+        -(1 << 100) - 12_345,
+        i128::MAX >> 1,
+    ];
+
+    #[test]
+    fn division_truncates_toward_zero() {
+        for a in VALUES {
+            for b in VALUES.into_iter().filter(|&b| b != 0) {
+                let (quotient, remainder) = big(a).div_rem(&big(b)).unwrap();
+
+                assert_eq!(quotient, big(a / b), "{a} / {b}");
+                assert_eq!(remainder, big(a % b), "{a} % {b}");
+            }
+        }
+
+        assert!(big(1).div_rem(&big(0)).is_none());
+    }
+
+    #[test]
+    fn shift_right_rounds_toward_negative_infinity() {
+        for a in VALUES {
+            for shift in [0, 1, 3, 31, 32, 33, 63, 64, 65, 100, 126] {
+                assert_eq!(big(a).shr(shift), big(a >> shift), "{a} >> {shift}");
+            }
+
+            let expected = if a < 0 { -1 } else { 0 };
+
+            assert_eq!(big(a).shr(usize::MAX), big(expected), "{a} >> max");
+        }
+    }
+
+    #[test]
+    fn shift_left_matches_multiplication() {
+        for a in [1, -1, 5, -(1 << 40) - 1] {
+            for shift in [0, 1, 31, 32, 33, 64, 80] {
+                assert_eq!(big(a).shl(shift), big(a << shift), "{a} << {shift}");
+            }
+        }
+    }
+
+    #[test]
+    fn bitwise_uses_twos_complement() {
+        for a in VALUES {
+            for b in VALUES {
+                assert_eq!(&big(a) & &big(b), big(a & b), "{a} & {b}");
+                assert_eq!(&big(a) | &big(b), big(a | b), "{a} | {b}");
+                assert_eq!(&big(a) ^ &big(b), big(a ^ b), "{a} ^ {b}");
+            }
+
+            assert_eq!(!&big(a), big(!a), "~{a}");
+        }
+    }
+
+    #[test]
+    fn converts_from_f64() {
+        assert_eq!(BigInt::from_f64(0.0), Some(big(0)));
+        assert_eq!(BigInt::from_f64(-0.0), Some(big(0)));
+        assert_eq!(BigInt::from_f64(-3.0), Some(big(-3)));
+        assert_eq!(BigInt::from_f64(2f64.powi(63)), Some(big(1 << 63)));
+        assert_eq!(BigInt::from_f64(-(2f64.powi(100))), Some(big(-(1 << 100))));
+        assert_eq!(BigInt::from_f64(0.5), None);
+        assert_eq!(BigInt::from_f64(f64::NAN), None);
+        assert_eq!(BigInt::from_f64(f64::INFINITY), None);
+
+        for num in [1.0, -1.0, 2f64.powi(53) + 2.0, 1e300, -1e300, f64::MAX] {
+            assert_eq!(BigInt::from_f64(num).unwrap().to_f64(), num);
+        }
+    }
+
+    #[test]
+    fn converts_to_i64_at_the_boundaries() {
+        for num in [0, 1, -1, i64::MAX, i64::MIN, i64::MIN + 1] {
+            assert_eq!(BigInt::from(num).to_i64(), Some(num));
+        }
+
+        assert_eq!(big(i64::MAX as i128 + 1).to_i64(), None);
+        assert_eq!(big(i64::MIN as i128 - 1).to_i64(), None);
+        assert_eq!(big(1 << 64).to_i64(), None);
+    }
+
+    #[test]
+    fn displays_in_decimal_chunks() {
+        for num in [
+            0,
+            -1,
+            999_999_999,
+            1_000_000_000,
+            -1_000_000_000,
+            1_000_000_000_000_000_001,
+            1 << 64,
+            -(1 << 100),
+            i128::MAX,
+        ] {
+            assert_eq!(big(num).to_string(), num.to_string());
+        }
+    }
+
+    #[test]
+    fn parses_in_any_radix() {
+        assert_eq!(BigInt::from_str_radix("ff", 16), Some(big(255)));
+        assert_eq!(
+            BigInt::from_str_radix("1".repeat(100).as_str(), 2),
+            Some(big((1 << 100) - 1))
+        );
+        assert_eq!(BigInt::from_str_radix("12a", 10), None);
+    }
+}
@@ -0,0 +1,297 @@
+mod big_int;
+
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    hash::{Hash, Hasher},
+    ops::Neg,
+    rc::Rc,
+};
+
+pub use big_int::BigInt;
+
+use crate::error::ArithmeticError;
+
+const MAX_BITS: u64 = 1 << 16;
+
+#[derive(Debug, Clone)]
+pub enum Number {
+    Integer(i64),
+    Big(Rc<BigInt>),
+    Float(f64),
+}
+
+impl Number {
+    pub fn as_f64(&self) -> f64 {
+        match self {
+            Self::Integer(num) => *num as f64,
+            Self::Big(num) => num.to_f64(),
+            Self::Float(num) => *num,
+        }
+    }
+
+    pub fn checked_add(self, other: Self) -> Result<Self, ArithmeticError> {
+        self.arithmetic(other, i64::checked_add, |a, b| Some(a + b), |a, b| a + b)
+    }
+
+    pub fn checked_sub(self, other: Self) -> Result<Self, ArithmeticError> {
+        self.arithmetic(other, i64::checked_sub, |a, b| Some(a - b), |a, b| a - b)
+    }
+
+    pub fn checked_mul(self, other: Self) -> Result<Self, ArithmeticError> {
+        self.arithmetic(other, i64::checked_mul, |a, b| Some(a * b), |a, b| a * b)
+    }
+
+    pub fn checked_div(self, other: Self) -> Result<Self, ArithmeticError> {
+        self.arithmetic(
+            other,
+            i64::checked_div,
+            |a, b| a.div_rem(b).map(|(quotient, _)| quotient),
+            |a, b| a / b,
+        )
+    }
+
+    pub fn checked_rem(self, other: Self) -> Result<Self, ArithmeticError> {
+        self.arithmetic(
+            other,
+            i64::checked_rem,
+            |a, b| a.div_rem(b).map(|(_, remainder)| remainder),
+            |a, b| a % b,
+        )
+    }
+
+    pub fn checked_pow(self, other: Self) -> Result<Self, ArithmeticError> {
+        match (&self, &other) {
+            (Self::Integer(base), Self::Integer(exponent)) if *exponent >= 0 => {
+                match u32::try_from(*exponent)
+                    .ok()
+                    .and_then(|exponent| base.checked_pow(exponent))
+                {
+                    Some(num) => Ok(Self::Integer(num)),
+                    None => Self::big_pow(&BigInt::from(*base), *exponent as u64),
+                }
+            }
+            (Self::Big(base), Self::Integer(exponent)) if *exponent >= 0 => {
+                Self::big_pow(base, *exponent as u64)
+            }
+            _ => Ok(Self::Float(self.as_f64().powf(other.as_f64()))),
+        }
+    }
+
+    pub fn bit_and(self, other: Self) -> Result<Self, ArithmeticError> {
+        self.bitwise(other, |a, b| a & b, |a, b| a & b)
+    }
+
+    pub fn bit_or(self, other: Self) -> Result<Self, ArithmeticError> {
+        self.bitwise(other, |a, b| a | b, |a, b| a | b)
+    }
+
+    pub fn bit_xor(self, other: Self) -> Result<Self, ArithmeticError> {
+        self.bitwise(other, |a, b| a ^ b, |a, b| a ^ b)
+    }
+
+    pub fn bit_not(self) -> Result<Self, ArithmeticError> {
+        match self {
+            Self::Integer(num) => Ok(Self::Integer(!num)),
+            Self::Big(num) => Ok(Self::from(!num.as_ref())),
+            Self::Float(_) => Err(ArithmeticError::NotInteger),
+        }
+    }
+
+    pub fn shl(self, other: Self) -> Result<Self, ArithmeticError> {
+        let shift = other.shift_amount()?;
+
+        match self {
+            Self::Integer(num) => match 1i64
+                .checked_shl(shift as u32)
+                .filter(|_| shift < 63)
+                .and_then(|factor| num.checked_mul(factor))
+            {
+                Some(num) => Ok(Self::Integer(num)),
+                None => Self::big_shl(&BigInt::from(num), shift),
+            },
+            Self::Big(num) => Self::big_shl(&num, shift),
+            Self::Float(_) => Err(ArithmeticError::NotInteger),
+        }
+    }
+
+    pub fn shr(self, other: Self) -> Result<Self, ArithmeticError> {
+        let shift = other.shift_amount()?;
+
+        match self {
+            Self::Integer(num) => Ok(Self::Integer(num >> shift.min(63))),
+            Self::Big(num) => Ok(Self::from(num.shr(shift))),
+            Self::Float(_) => Err(ArithmeticError::NotInteger),
+        }
+    }
+
+    fn big_pow(base: &BigInt, exponent: u64) -> Result<Self, ArithmeticError> {
+        let bits = base.bits();
+
+        if bits > 1 && (bits - 1).saturating_mul(exponent) >= MAX_BITS {
+            return Err(ArithmeticError::TooLarge);
+        }
+
+        Ok(Self::from(base.pow(exponent)))
+    }
+
+    fn big_shl(num: &BigInt, shift: usize) -> Result<Self, ArithmeticError> {
+        let bits = num.bits();
+
+        if bits > 0 && bits.saturating_add(shift as u64) > MAX_BITS {
+            return Err(ArithmeticError::TooLarge);
+        }
+
+        Ok(Self::from(num.shl(shift)))
+    }
+
+    fn shift_amount(&self) -> Result<usize, ArithmeticError> {
+        match self {
+            Self::Integer(num) => usize::try_from(*num).or(Err(ArithmeticError::NegativeShift)),
+            Self::Big(num) if num.is_negative() => Err(ArithmeticError::NegativeShift),
+            Self::Big(_) => Ok(usize::MAX),
+            Self::Float(_) => Err(ArithmeticError::NotInteger),
+        }
+    }
+
+    fn bitwise(
+        self,
+        other: Self,
+        integer: fn(i64, i64) -> i64,
+        big: fn(&BigInt, &BigInt) -> BigInt,
+    ) -> Result<Self, ArithmeticError> {
+        match (&self, &other) {
+            (Self::Integer(a), Self::Integer(b)) => Ok(Self::Integer(integer(*a, *b))),
+            (Self::Float(_), _) | (_, Self::Float(_)) => Err(ArithmeticError::NotInteger),
+            (a, b) => match (a.to_big(), b.to_big()) {
+                (Some(a), Some(b)) => Ok(Self::from(big(&a, &b))),
+                _ => Err(ArithmeticError::NotInteger),
+            },
+        }
+    }
+
+    fn to_big(&self) -> Option<BigInt> {
+        match self {
+            Self::Integer(num) => Some(BigInt::from(*num)),
+            Self::Big(num) => Some(num.as_ref().clone()),
+            Self::Float(num) => BigInt::from_f64(*num),
+        }
+    }
+
+    fn compare_float(&self, float: f64) -> Option<Ordering> {
+        if float.is_nan() {
+            return None;
+        }
+
+        if float.is_infinite() {
+            return Some(if float > 0.0 {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            });
+        }
+
+        let integral = BigInt::from_f64(float.trunc())?;
+
+        match self.to_big()?.partial_cmp(&integral)? {
+            Ordering::Equal => 0.0.partial_cmp(&float.fract()),
+            ordering => Some(ordering),
+        }
+    }
+
+    fn arithmetic(
+        self,
+        other: Self,
+        integer: fn(i64, i64) -> Option<i64>,
+        big: fn(&BigInt, &BigInt) -> Option<BigInt>,
+        float: fn(f64, f64) -> f64,
+    ) -> Result<Self, ArithmeticError> {
+        match (&self, &other) {
+            (Self::Float(_), _) | (_, Self::Float(_)) => {
+                Ok(Self::Float(float(self.as_f64(), other.as_f64())))
+            }
+            (Self::Integer(a), Self::Integer(b)) if *b != 0 => match integer(*a, *b) {
+                Some(num) => Ok(Self::Integer(num)),
+                None => big(&BigInt::from(*a), &BigInt::from(*b))
+                    .map(Self::from)
+                    .ok_or(ArithmeticError::DivisionByZero),
+            },
+            (a, b) => match (a.to_big(), b.to_big()) {
+                (Some(a), Some(b)) => match big(&a, &b) {
+                    Some(num) if num.bits() > MAX_BITS => Err(ArithmeticError::TooLarge),
+                    Some(num) => Ok(Self::from(num)),
+                    None => Err(ArithmeticError::DivisionByZero),
+                },
+                _ => Err(ArithmeticError::DivisionByZero),
+            },
+        }
+    }
+}
+
+impl From<BigInt> for Number {
+    fn from(value: BigInt) -> Self {
+        match value.to_i64() {
+            Some(num) => Self::Integer(num),
+            None => Self::Big(Rc::new(value)),
+        }
+    }
+}
+
+impl Neg for Number {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        match self {
+            Self::Integer(num) => match num.checked_neg() {
+                Some(num) => Self::Integer(num),
+                None => Self::from(-&BigInt::from(num)),
+            },
+            Self::Big(num) => Self::from(-num.as_ref()),
+            Self::Float(num) => Self::Float(-num),
+        }
+    }
+}
+
+impl PartialEq for Number {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Integer(a), Self::Integer(b)) => a == b,
+            (Self::Big(a), Self::Big(b)) => a == b,
+            (Self::Float(a), Self::Float(b)) => a == b,
+            (a, b) => a.to_big().is_some_and(|a| Some(a) == b.to_big()),
+        }
+    }
+}
+
+impl PartialOrd for Number {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Self::Integer(a), Self::Integer(b)) => a.partial_cmp(b),
+            (Self::Float(a), Self::Float(b)) => a.partial_cmp(b),
+            (Self::Float(a), b) => b.compare_float(*a).map(Ordering::reverse),
+            (a, Self::Float(b)) => a.compare_float(*b),
+            (a, b) => a.to_big().partial_cmp(&b.to_big()),
+        }
+    }
+}
+
+impl Hash for Number {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self.to_big().map(Self::from) {
+            Some(Self::Integer(num)) => num.hash(state),
+            Some(Self::Big(num)) => num.hash(state),
+            _ => self.as_f64().to_bits().hash(state),
+        }
+    }
+}
+
+impl Display for Number {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Integer(num) => write!(f, "{num}"),
+            Self::Big(num) => write!(f, "{num}"),
+            Self::Float(num) if num.is_finite() && num.fract() == 0.0 => write!(f, "{num:.1}"),
+            Self::Float(num) => write!(f, "{num}"),
+        }
+    }
+}
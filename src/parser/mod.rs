@@ -1,28 +1,690 @@
+use std::{cell::Cell, rc::Rc};
+
 use crate::{
-    Result,
-    ast::{Expr, Literal},
+    ast::{Argument, Expr, FunctionDecl, Literal, MatchArm, Param, Pattern, Stmt},
     error::{Error, ParserError},
-    scanner::token::{Token, TokenType},
+    number::Number,
+    result::Result,
+    scanner::token::{Span, Token, TokenType},
 };
 
+pub const DEFAULT_MAX_DEPTH: usize = 256;
+
 pub struct Parser<'a> {
     tokens: &'a [Token],
+    statements: Vec<Stmt>,
+    errors: Vec<Error>,
     current: usize,
+    depth: usize,
+    max_depth: usize,
 }
 
 impl<'a> Parser<'a> {
     pub fn new(tokens: &'a [Token]) -> Self {
-        Self { tokens, current: 0 }
+        Self {
+            tokens,
+            statements: Vec::new(),
+            errors: Vec::new(),
+            current: 0,
+            depth: 0,
+            max_depth: DEFAULT_MAX_DEPTH,
+        }
+    }
+
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    pub fn parse(&mut self) -> Vec<Error> {
+        while !self.is_at_end() {
+            let statement = self.declaration();
+
+            self.statements.push(statement);
+        }
+
+        std::mem::take(&mut self.errors)
+    }
+
+    pub fn statements(&self) -> &[Stmt] {
+        &self.statements
+    }
+
+    fn declaration(&mut self) -> Stmt {
+        let start = self.current;
+
+        match self.nested(Self::try_declaration) {
+            Ok(stmt) => stmt,
+            Err(e) => {
+                self.errors.push(e);
+                self.synchronize();
+
+                if self.current == start {
+                    self.advance();
+                }
+
+                Stmt::Error(self.span_from(start))
+            }
+        }
+    }
+
+    fn try_declaration(&mut self) -> Result<Stmt> {
+        if self.match_token_type(&[TokenType::Class]) {
+            return self.class_declaration();
+        }
+        if self.check(&TokenType::Fun)
+            && self
+                .peek_next()
+                .is_some_and(|token| matches!(token.token_type(), TokenType::Identifier(_)))
+        {
+            self.advance();
+            return Ok(Stmt::Function(self.function()?));
+        }
+        if self.match_token_type(&[TokenType::Var]) {
+            return self.var_declaration();
+        }
+
+        self.statement()
+    }
+
+    fn class_declaration(&mut self) -> Result<Stmt> {
+        let name = self.consume_identifier()?;
+
+        let superclass = if self.match_token_type(&[TokenType::Less]) {
+            Some(Box::new(Expr::Variable {
+                name: self.consume_identifier()?,
+                depth: Cell::new(None),
+            }))
+        } else {
+            None
+        };
+
+        self.consume(TokenType::LeftBrace)?;
+
+        let mut methods = Vec::new();
+
+        while !self.check(&TokenType::RightBrace) && !self.is_at_end() {
+            methods.push(self.function()?);
+        }
+
+        self.consume(TokenType::RightBrace)?;
+
+        Ok(Stmt::Class {
+            name,
+            superclass,
+            methods,
+        })
+    }
+
+    fn function(&mut self) -> Result<Rc<FunctionDecl>> {
+        let name = self.consume_identifier()?;
+        let (params, rest) = self.parameters()?;
+
+        self.consume(TokenType::LeftBrace)?;
+
+        let body = self.block()?;
+
+        Ok(Rc::new(FunctionDecl {
+            name,
+            params,
+            rest,
+            body,
+        }))
+    }
+
+    fn parameters(&mut self) -> Result<(Vec<Param>, Option<Token>)> {
+        self.consume(TokenType::LeftParen)?;
+
+        let mut params: Vec<Param> = Vec::new();
+        let mut rest = None;
+
+        if !self.check(&TokenType::RightParen) {
+            loop {
+                if self.match_token_type(&[TokenType::Ellipsis]) {
+                    rest = Some(self.consume_identifier()?);
+
+                    break;
+                }
+
+                let name = self.consume_identifier()?;
+
+                let default = if self.match_token_type(&[TokenType::Equal]) {
+                    Some(self.nested(Self::assignment)?)
+                } else {
+                    None
+                };
+
+                if default.is_none() && params.last().is_some_and(|param| param.default.is_some()) {
+                    self.errors
+                        .push(Error::Parser(ParserError::RequiredAfterDefault {
+                            name: name.clone(),
+                        }));
+                }
+
+                params.push(Param { name, default });
+
+                if !self.match_token_type(&[TokenType::Comma]) {
+                    break;
+                }
+            }
+        }
+
+        self.consume(TokenType::RightParen)?;
+
+        Ok((params, rest))
+    }
+
+    fn var_declaration(&mut self) -> Result<Stmt> {
+        let name = self.consume_identifier()?;
+
+        let initializer = if self.match_token_type(&[TokenType::Equal]) {
+            Some(self.expression()?)
+        } else {
+            None
+        };
+
+        self.consume(TokenType::Semicolon)?;
+
+        Ok(Stmt::Var { name, initializer })
+    }
+
+    fn statement(&mut self) -> Result<Stmt> {
+        if let TokenType::Identifier(_) = self.peek().token_type()
+            && self
+                .peek_next()
+                .is_some_and(|token| *token.token_type() == TokenType::Colon)
+        {
+            return self.labelled_statement();
+        }
+        if self.match_token_type(&[TokenType::For]) {
+            return self.for_statement(None);
+        }
+        if self.match_token_type(&[TokenType::If]) {
+            return self.if_statement();
+        }
+        if self.match_token_type(&[TokenType::While]) {
+            return self.while_statement(None);
+        }
+        if self.match_token_type(&[TokenType::Break, TokenType::Continue]) {
+            return self.jump_statement();
+        }
+        if self.match_token_type(&[TokenType::Match]) {
+            return self.match_statement();
+        }
+        if self.match_token_type(&[TokenType::Print]) {
+            return self.print_statement();
+        }
+        if self.match_token_type(&[TokenType::Return]) {
+            return self.return_statement();
+        }
+        if self.match_token_type(&[TokenType::LeftBrace]) {
+            return Ok(Stmt::Block(self.block()?));
+        }
+
+        self.expression_statement()
+    }
+
+    fn block(&mut self) -> Result<Vec<Stmt>> {
+        let mut statements = Vec::new();
+
+        while !self.check(&TokenType::RightBrace) && !self.is_at_end() {
+            statements.push(self.declaration());
+        }
+
+        self.consume(TokenType::RightBrace)?;
+
+        Ok(statements)
+    }
+
+    fn labelled_statement(&mut self) -> Result<Stmt> {
+        let label = self.advance().clone();
+
+        self.consume(TokenType::Colon)?;
+
+        if self.match_token_type(&[TokenType::For]) {
+            return self.for_statement(Some(label));
+        }
+        if self.match_token_type(&[TokenType::While]) {
+            return self.while_statement(Some(label));
+        }
+
+        Err(Error::Parser(ParserError::ExpectedLoop {
+            token: self.peek().clone(),
+        }))
+    }
+
+    fn for_statement(&mut self, label: Option<Token>) -> Result<Stmt> {
+        self.consume(TokenType::LeftParen)?;
+
+        let initializer = if self.match_token_type(&[TokenType::Semicolon]) {
+            None
+        } else if self.match_token_type(&[TokenType::Var]) {
+            Some(self.var_declaration()?)
+        } else {
+            Some(self.expression_statement()?)
+        };
+
+        let condition = if self.check(&TokenType::Semicolon) {
+            Box::new(Expr::Literal {
+                value: Literal::Bool(true),
+                span: self.peek().span(),
+            })
+        } else {
+            self.expression()?
+        };
+
+        self.consume(TokenType::Semicolon)?;
+
+        let increment = if self.check(&TokenType::RightParen) {
+            None
+        } else {
+            Some(self.expression()?)
+        };
+
+        self.consume(TokenType::RightParen)?;
+
+        let body = Box::new(self.nested(Self::statement)?);
+
+        let mut body = Stmt::While {
+            label,
+            condition,
+            body,
+            increment,
+        };
+
+        if let Some(initializer) = initializer {
+            body = Stmt::Block(vec![initializer, body]);
+        }
+
+        Ok(body)
+    }
+
+    fn if_statement(&mut self) -> Result<Stmt> {
+        let mut branches = Vec::new();
+        let mut else_branch = None;
+
+        loop {
+            self.consume(TokenType::LeftParen)?;
+
+            let condition = self.expression()?;
+
+            self.consume(TokenType::RightParen)?;
+
+            branches.push((condition, Box::new(self.nested(Self::statement)?)));
+
+            if !self.match_token_type(&[TokenType::Else]) {
+                break;
+            }
+
+            if !self.match_token_type(&[TokenType::If]) {
+                else_branch = Some(Box::new(self.nested(Self::statement)?));
+                break;
+            }
+        }
+
+        // An `else if` chain is parsed as a flat list of branches so that it
+        // doesn't count towards the nesting depth, then folded back up here.
+        for (condition, then_branch) in branches.into_iter().rev() {
+            else_branch = Some(Box::new(Stmt::If {
+                condition,
+                then_branch,
+                else_branch,
+            }));
+        }
+
+        Ok(*else_branch.expect("an if statement has at least one branch"))
+    }
+
+    fn while_statement(&mut self, label: Option<Token>) -> Result<Stmt> {
+        self.consume(TokenType::LeftParen)?;
+
+        let condition = self.expression()?;
+
+        self.consume(TokenType::RightParen)?;
+
+        let body = Box::new(self.nested(Self::statement)?);
+
+        Ok(Stmt::While {
+            label,
+            condition,
+            body,
+            increment: None,
+        })
+    }
+
+    fn match_statement(&mut self) -> Result<Stmt> {
+        let keyword = self.previous().clone();
+
+        self.consume(TokenType::LeftParen)?;
+
+        let scrutinee = self.expression()?;
+
+        self.consume(TokenType::RightParen)?;
+        self.consume(TokenType::LeftBrace)?;
+
+        let mut arms = Vec::new();
+
+        while !self.check(&TokenType::RightBrace) && !self.is_at_end() {
+            let pattern = self.pattern()?;
+            let guard = if self.match_token_type(&[TokenType::If]) {
+                Some(self.expression()?)
+            } else {
+                None
+            };
+
+            self.consume(TokenType::FatArrow)?;
+
+            let body = self.nested(Self::statement)?;
+
+            arms.push(MatchArm {
+                pattern,
+                guard,
+                body,
+            });
+        }
+
+        self.consume(TokenType::RightBrace)?;
+
+        Ok(Stmt::Match {
+            keyword,
+            scrutinee,
+            arms,
+        })
+    }
+
+    fn pattern(&mut self) -> Result<Pattern> {
+        let span = self.peek().span();
+
+        match self.peek().token_type() {
+            TokenType::Identifier(identifier) => {
+                let wildcard = identifier == "_";
+                let name = self.advance().clone();
+
+                if self.match_token_type(&[TokenType::Colon]) {
+                    return Ok(Pattern::Class {
+                        name: (!wildcard).then_some(name),
+                        class: self.consume_identifier()?,
+                        depth: Cell::new(None),
+                    });
+                }
+
+                Ok(if wildcard {
+                    Pattern::Wildcard
+                } else {
+                    Pattern::Binding(name)
+                })
+            }
+            TokenType::Nil => {
+                self.advance();
+                Ok(Pattern::Literal {
+                    value: Literal::Nil,
+                    span,
+                })
+            }
+            TokenType::True | TokenType::False => {
+                let value = *self.advance().token_type() == TokenType::True;
+                Ok(Pattern::Literal {
+                    value: Literal::Bool(value),
+                    span,
+                })
+            }
+            TokenType::String(s) => {
+                let s: String = s.into();
+                self.advance();
+                Ok(Pattern::Literal {
+                    value: Literal::String(s),
+                    span,
+                })
+            }
+            TokenType::Minus | TokenType::Number(_) => {
+                let start = self.pattern_number()?;
+
+                if !self.match_token_type(&[TokenType::DotDot, TokenType::DotDotEqual]) {
+                    return Ok(Pattern::Literal {
+                        value: Literal::Number(start),
+                        span,
+                    });
+                }
+
+                let inclusive = *self.previous().token_type() == TokenType::DotDotEqual;
+                let end = self.pattern_number()?;
+
+                Ok(Pattern::Range {
+                    start,
+                    end,
+                    inclusive,
+                    span,
+                })
+            }
+            _ => Err(Error::Parser(ParserError::ExpectedPattern {
+                token: self.peek().clone(),
+            })),
+        }
+    }
+
+    fn pattern_number(&mut self) -> Result<Number> {
+        let negative = self.match_token_type(&[TokenType::Minus]);
+
+        let TokenType::Number(num) = self.peek().token_type() else {
+            return Err(Error::Parser(ParserError::ExpectedPattern {
+                token: self.peek().clone(),
+            }));
+        };
+
+        let num = num.clone();
+        self.advance();
+
+        Ok(if negative { -num } else { num })
+    }
+
+    fn print_statement(&mut self) -> Result<Stmt> {
+        let keyword = self.previous().clone();
+        let value = self.expression()?;
+
+        self.consume(TokenType::Semicolon)?;
+
+        Ok(Stmt::Print { keyword, value })
     }
 
-    pub fn parse(&mut self) -> Result<Box<Expr>> {
-        self.expression().inspect_err(|_| {
-            self.synchronize();
+    fn jump_statement(&mut self) -> Result<Stmt> {
+        let keyword = self.previous().clone();
+
+        let label = if let TokenType::Identifier(_) = self.peek().token_type() {
+            Some(self.advance().clone())
+        } else {
+            None
+        };
+
+        self.consume(TokenType::Semicolon)?;
+
+        Ok(match keyword.token_type() {
+            TokenType::Break => Stmt::Break { keyword, label },
+            _ => Stmt::Continue { keyword, label },
         })
     }
 
+    fn return_statement(&mut self) -> Result<Stmt> {
+        let keyword = self.previous().clone();
+
+        let value = if self.check(&TokenType::Semicolon) {
+            None
+        } else {
+            Some(self.expression()?)
+        };
+
+        self.consume(TokenType::Semicolon)?;
+
+        Ok(Stmt::Return { keyword, value })
+    }
+
+    fn expression_statement(&mut self) -> Result<Stmt> {
+        let expr = self.expression()?;
+
+        self.consume(TokenType::Semicolon)?;
+
+        Ok(Stmt::Expression(expr))
+    }
+
     fn expression(&mut self) -> Result<Box<Expr>> {
-        self.equality()
+        self.nested(Self::comma)
+    }
+
+    fn comma(&mut self) -> Result<Box<Expr>> {
+        let expr = self.assignment()?;
+
+        if !self.check(&TokenType::Comma) {
+            return Ok(expr);
+        }
+
+        let mut exprs = vec![*expr];
+
+        while self.match_token_type(&[TokenType::Comma]) {
+            exprs.push(*self.assignment()?);
+        }
+
+        Ok(Box::new(Expr::Comma(exprs)))
+    }
+
+    fn assignment(&mut self) -> Result<Box<Expr>> {
+        let start = self.current;
+        let expr = self.conditional()?;
+
+        if self.match_token_type(&[TokenType::Equal]) {
+            let equals = self.previous().clone();
+            let value = self.nested(Self::assignment)?;
+
+            match *expr {
+                Expr::Variable { name, .. } => {
+                    return Ok(Box::new(Expr::Assign {
+                        name,
+                        value,
+                        depth: Cell::new(None),
+                    }));
+                }
+                Expr::Get { object, name } => {
+                    return Ok(Box::new(Expr::Set {
+                        object,
+                        name,
+                        value,
+                    }));
+                }
+                _ => {}
+            }
+
+            self.errors
+                .push(Error::Parser(ParserError::InvalidAssignmentTarget {
+                    token: equals,
+                }));
+
+            return Ok(Box::new(Expr::Error(self.span_from(start))));
+        }
+
+        if let Some(token_type) = self.peek().token_type().compound_operator() {
+            let token = self.advance();
+            let lexeme = token.lexeme().strip_suffix('=').unwrap_or(token.lexeme());
+            let operator = Token::new(token_type, lexeme.into(), token.span());
+            let value = self.nested(Self::assignment)?;
+
+            match *expr {
+                Expr::Variable { name, .. } => {
+                    return Ok(Box::new(Expr::CompoundAssign {
+                        name,
+                        operator,
+                        value,
+                        depth: Cell::new(None),
+                    }));
+                }
+                Expr::Get { object, name } => {
+                    return Ok(Box::new(Expr::CompoundSet {
+                        object,
+                        name,
+                        operator,
+                        value,
+                    }));
+                }
+                _ => {}
+            }
+
+            self.errors
+                .push(Error::Parser(ParserError::InvalidAssignmentTarget {
+                    token: operator,
+                }));
+
+            return Ok(Box::new(Expr::Error(self.span_from(start))));
+        }
+
+        Ok(expr)
+    }
+
+    fn conditional(&mut self) -> Result<Box<Expr>> {
+        let condition = self.coalesce()?;
+
+        if !self.match_token_type(&[TokenType::Question]) {
+            return Ok(condition);
+        }
+
+        let question = self.previous().clone();
+        let then_branch = self.nested(Self::comma)?;
+
+        if !self.match_token_type(&[TokenType::Colon]) {
+            return Err(Error::Parser(ParserError::MissingColon { question }));
+        }
+
+        let else_branch = self.nested(Self::conditional)?;
+
+        Ok(Box::new(Expr::Conditional {
+            condition,
+            then_branch,
+            else_branch,
+        }))
+    }
+
+    fn coalesce(&mut self) -> Result<Box<Expr>> {
+        let mut expr = self.or()?;
+
+        while self.match_token_type(&[TokenType::QuestionQuestion]) {
+            let operator = self.previous().clone();
+            let right = self.or()?;
+            expr = Box::new(Expr::Coalesce {
+                left: expr,
+                operator,
+                right,
+            });
+        }
+
+        Ok(expr)
+    }
+
+    fn or(&mut self) -> Result<Box<Expr>> {
+        let mut expr = self.and()?;
+
+        while self.match_token_type(&[TokenType::Or]) {
+            let operator = self.previous().clone();
+            let right = self.and()?;
+            expr = Box::new(Expr::Logical {
+                left: expr,
+                operator,
+                right,
+            });
+        }
+
+        Ok(expr)
+    }
+
+    fn and(&mut self) -> Result<Box<Expr>> {
+        let mut expr = self.equality()?;
+
+        while self.match_token_type(&[TokenType::And]) {
+            let operator = self.previous().clone();
+            let right = self.equality()?;
+            expr = Box::new(Expr::Logical {
+                left: expr,
+                operator,
+                right,
+            });
+        }
+
+        Ok(expr)
     }
 
     fn equality(&mut self) -> Result<Box<Expr>> {
@@ -42,7 +704,7 @@ impl<'a> Parser<'a> {
     }
 
     fn comparison(&mut self) -> Result<Box<Expr>> {
-        let mut expr = self.term()?;
+        let mut expr = self.bit_or()?;
 
         while self.match_token_type(&[
             TokenType::Greater,
@@ -50,6 +712,70 @@ impl<'a> Parser<'a> {
             TokenType::Less,
             TokenType::LessEqual,
         ]) {
+            let operator = self.previous().clone();
+            let right = self.bit_or()?;
+            expr = Box::new(Expr::Binary {
+                left: expr,
+                operator,
+                right,
+            });
+        }
+
+        Ok(expr)
+    }
+
+    fn bit_or(&mut self) -> Result<Box<Expr>> {
+        let mut expr = self.bit_xor()?;
+
+        while self.match_token_type(&[TokenType::Pipe]) {
+            let operator = self.previous().clone();
+            let right = self.bit_xor()?;
+            expr = Box::new(Expr::Binary {
+                left: expr,
+                operator,
+                right,
+            });
+        }
+
+        Ok(expr)
+    }
+
+    fn bit_xor(&mut self) -> Result<Box<Expr>> {
+        let mut expr = self.bit_and()?;
+
+        while self.match_token_type(&[TokenType::Caret]) {
+            let operator = self.previous().clone();
+            let right = self.bit_and()?;
+            expr = Box::new(Expr::Binary {
+                left: expr,
+                operator,
+                right,
+            });
+        }
+
+        Ok(expr)
+    }
+
+    fn bit_and(&mut self) -> Result<Box<Expr>> {
+        let mut expr = self.shift()?;
+
+        while self.match_token_type(&[TokenType::Ampersand]) {
+            let operator = self.previous().clone();
+            let right = self.shift()?;
+            expr = Box::new(Expr::Binary {
+                left: expr,
+                operator,
+                right,
+            });
+        }
+
+        Ok(expr)
+    }
+
+    fn shift(&mut self) -> Result<Box<Expr>> {
+        let mut expr = self.term()?;
+
+        while self.match_token_type(&[TokenType::LessLess, TokenType::GreaterGreater]) {
             let operator = self.previous().clone();
             let right = self.term()?;
             expr = Box::new(Expr::Binary {
@@ -81,7 +807,7 @@ impl<'a> Parser<'a> {
     fn factor(&mut self) -> Result<Box<Expr>> {
         let mut expr = self.unary()?;
 
-        while self.match_token_type(&[TokenType::Slash, TokenType::Star]) {
+        while self.match_token_type(&[TokenType::Slash, TokenType::Star, TokenType::Percent]) {
             let operator = self.previous().clone();
             let right = self.unary()?;
             expr = Box::new(Expr::Binary {
@@ -95,24 +821,114 @@ impl<'a> Parser<'a> {
     }
 
     fn unary(&mut self) -> Result<Box<Expr>> {
-        if self.match_token_type(&[TokenType::Bang, TokenType::Minus]) {
+        if self.match_token_type(&[TokenType::Bang, TokenType::Minus, TokenType::Tilde]) {
             let operator = self.previous().clone();
-            let right = self.unary()?;
+            let right = self.nested(Self::unary)?;
             return Ok(Box::new(Expr::Unary { operator, right }));
         }
 
-        self.primary()
+        self.power()
+    }
+
+    fn power(&mut self) -> Result<Box<Expr>> {
+        let expr = self.call()?;
+
+        if self.match_token_type(&[TokenType::StarStar]) {
+            let operator = self.previous().clone();
+            let right = self.nested(Self::unary)?;
+
+            return Ok(Box::new(Expr::Binary {
+                left: expr,
+                operator,
+                right,
+            }));
+        }
+
+        Ok(expr)
+    }
+
+    fn call(&mut self) -> Result<Box<Expr>> {
+        let mut expr = self.primary()?;
+
+        let mut optional = false;
+
+        loop {
+            if self.match_token_type(&[TokenType::LeftParen]) {
+                expr = self.finish_call(expr)?;
+            } else if self.match_token_type(&[TokenType::Dot]) {
+                let name = self.consume_identifier()?;
+                expr = Box::new(Expr::Get { object: expr, name });
+            } else if self.match_token_type(&[TokenType::QuestionDot]) {
+                let name = self.consume_identifier()?;
+                expr = Box::new(Expr::OptionalGet { object: expr, name });
+                optional = true;
+            } else {
+                break;
+            }
+        }
+
+        if optional {
+            expr = Box::new(Expr::Chain(expr));
+        }
+
+        Ok(expr)
+    }
+
+    fn finish_call(&mut self, callee: Box<Expr>) -> Result<Box<Expr>> {
+        let mut arguments = Vec::new();
+
+        if !self.check(&TokenType::RightParen) {
+            loop {
+                arguments.push(self.argument()?);
+
+                if !self.match_token_type(&[TokenType::Comma]) {
+                    break;
+                }
+            }
+        }
+
+        let paren = self.consume(TokenType::RightParen)?.clone();
+
+        Ok(Box::new(Expr::Call {
+            callee,
+            paren,
+            arguments,
+        }))
+    }
+
+    fn argument(&mut self) -> Result<Argument> {
+        if self.match_token_type(&[TokenType::Ellipsis]) {
+            let ellipsis = self.previous().clone();
+            let value = *self.nested(Self::assignment)?;
+
+            return Ok(Argument::Spread { ellipsis, value });
+        }
+
+        if matches!(self.peek().token_type(), TokenType::Identifier(_))
+            && self
+                .peek_next()
+                .is_some_and(|token| *token.token_type() == TokenType::Colon)
+        {
+            let name = self.advance().clone();
+            self.advance();
+
+            let value = *self.nested(Self::assignment)?;
+
+            return Ok(Argument::Named { name, value });
+        }
+
+        Ok(Argument::Positional(*self.nested(Self::assignment)?))
     }
 
     fn primary(&mut self) -> Result<Box<Expr>> {
         if self.match_token_type(&[TokenType::False]) {
-            return Ok(Box::new(Expr::Literal(Literal::Bool(false))));
+            return Ok(self.literal(Literal::Bool(false)));
         }
         if self.match_token_type(&[TokenType::True]) {
-            return Ok(Box::new(Expr::Literal(Literal::Bool(true))));
+            return Ok(self.literal(Literal::Bool(true)));
         }
         if self.match_token_type(&[TokenType::Nil]) {
-            return Ok(Box::new(Expr::Literal(Literal::Nil)));
+            return Ok(self.literal(Literal::Nil));
         }
 
         if self.is_at_end() {
@@ -122,16 +938,53 @@ impl<'a> Parser<'a> {
         }
 
         if let TokenType::Number(num) = self.peek().token_type() {
-            let num = *num;
+            let num = num.clone();
             self.advance();
-            return Ok(Box::new(Expr::Literal(Literal::Number(num))));
+            return Ok(self.literal(Literal::Number(num)));
         }
         if let TokenType::String(s) = self.peek().token_type() {
             let s: String = s.into();
             self.advance();
-            return Ok(Box::new(Expr::Literal(Literal::String(s))));
+            return Ok(self.literal(Literal::String(s)));
+        }
+        if let TokenType::Interpolation(s) = self.peek().token_type() {
+            let s: String = s.into();
+            self.advance();
+            return self.interpolation(s);
         }
+        if let TokenType::Identifier(_) = self.peek().token_type() {
+            let name = self.advance().clone();
+            return Ok(Box::new(Expr::Variable {
+                name,
+                depth: Cell::new(None),
+            }));
+        }
+        if self.match_token_type(&[TokenType::This]) {
+            return Ok(Box::new(Expr::This {
+                keyword: self.previous().clone(),
+                depth: Cell::new(None),
+            }));
+        }
+        if self.match_token_type(&[TokenType::Super]) {
+            let keyword = self.previous().clone();
+
+            self.consume(TokenType::Dot)?;
 
+            let method = self.consume_identifier()?;
+
+            return Ok(Box::new(Expr::Super {
+                keyword,
+                method,
+                depth: Cell::new(None),
+            }));
+        }
+
+        if self.match_token_type(&[TokenType::Fun]) {
+            return self.lambda();
+        }
+        if self.check(&TokenType::LeftParen) && self.is_arrow_function() {
+            return self.arrow_function();
+        }
         if self.match_token_type(&[TokenType::LeftParen]) {
             let expr = self.expression()?;
 
@@ -145,6 +998,148 @@ impl<'a> Parser<'a> {
         }))
     }
 
+    fn lambda(&mut self) -> Result<Box<Expr>> {
+        let name = Self::lambda_name(self.previous());
+        let (params, rest) = self.parameters()?;
+
+        self.consume(TokenType::LeftBrace)?;
+
+        let body = self.block()?;
+
+        Ok(Box::new(Expr::Lambda(Rc::new(FunctionDecl {
+            name,
+            params,
+            rest,
+            body,
+        }))))
+    }
+
+    fn arrow_function(&mut self) -> Result<Box<Expr>> {
+        let name = Self::lambda_name(self.peek());
+        let (params, rest) = self.parameters()?;
+        let arrow = self.consume(TokenType::FatArrow)?.clone();
+
+        let body = if self.match_token_type(&[TokenType::LeftBrace]) {
+            self.block()?
+        } else {
+            let value = self.nested(Self::assignment)?;
+
+            vec![Stmt::Return {
+                keyword: arrow,
+                value: Some(value),
+            }]
+        };
+
+        Ok(Box::new(Expr::Lambda(Rc::new(FunctionDecl {
+            name,
+            params,
+            rest,
+            body,
+        }))))
+    }
+
+    fn lambda_name(token: &Token) -> Token {
+        Token::new(
+            TokenType::Identifier("lambda".into()),
+            token.lexeme().into(),
+            token.span(),
+        )
+    }
+
+    fn is_arrow_function(&self) -> bool {
+        let mut parens = 0usize;
+        let mut braces = 0usize;
+        let mut parameter_start = false;
+
+        for (i, token) in self.tokens[self.current..].iter().enumerate() {
+            if parameter_start
+                && !matches!(
+                    token.token_type(),
+                    TokenType::Identifier(_) | TokenType::Ellipsis | TokenType::RightParen
+                )
+            {
+                return false;
+            }
+
+            parameter_start = false;
+
+            match token.token_type() {
+                TokenType::LeftParen => {
+                    parens += 1;
+                    parameter_start = parens == 1;
+                }
+                TokenType::RightParen => {
+                    parens -= 1;
+
+                    if parens == 0 {
+                        return self
+                            .tokens
+                            .get(self.current + i + 1)
+                            .is_some_and(|token| *token.token_type() == TokenType::FatArrow);
+                    }
+                }
+                TokenType::Comma => parameter_start = parens == 1 && braces == 0,
+                TokenType::LeftBrace => braces += 1,
+                TokenType::RightBrace if braces == 0 => break,
+                TokenType::RightBrace => braces -= 1,
+                TokenType::Semicolon
+                | TokenType::Break
+                | TokenType::Class
+                | TokenType::Continue
+                | TokenType::For
+                | TokenType::If
+                | TokenType::Match
+                | TokenType::Print
+                | TokenType::Return
+                | TokenType::Var
+                | TokenType::While
+                    if braces == 0 =>
+                {
+                    break;
+                }
+                TokenType::Eof => break,
+                _ => {}
+            }
+        }
+
+        false
+    }
+
+    fn literal(&self, value: Literal) -> Box<Expr> {
+        Box::new(Expr::Literal {
+            value,
+            span: self.previous().span(),
+        })
+    }
+
+    fn interpolation(&mut self, head: String) -> Result<Box<Expr>> {
+        let mut parts = vec![*self.literal(Literal::String(head))];
+
+        loop {
+            parts.push(*self.expression()?);
+
+            match self.peek().token_type() {
+                TokenType::Interpolation(s) => {
+                    let s = s.into();
+                    self.advance();
+                    parts.push(*self.literal(Literal::String(s)));
+                }
+                TokenType::InterpolationEnd(s) => {
+                    let s = s.into();
+                    self.advance();
+                    parts.push(*self.literal(Literal::String(s)));
+
+                    return Ok(Box::new(Expr::Interpolation(parts)));
+                }
+                _ => {
+                    return Err(Error::Parser(ParserError::ExpectedOther {
+                        token: self.peek().clone(),
+                    }));
+                }
+            }
+        }
+    }
+
     fn consume(&mut self, token_type: TokenType) -> Result<&Token> {
         if self.check(&token_type) {
             return Ok(self.advance());
@@ -156,30 +1151,74 @@ impl<'a> Parser<'a> {
         }))
     }
 
+    fn consume_identifier(&mut self) -> Result<Token> {
+        if let TokenType::Identifier(_) = self.peek().token_type() {
+            return Ok(self.advance().clone());
+        }
+
+        Err(Error::Parser(ParserError::ExpectedIdentifier {
+            token: self.peek().clone(),
+        }))
+    }
+
     fn synchronize(&mut self) {
-        self.advance();
+        let mut braces = 0usize;
 
         while !self.is_at_end() {
-            if *self.previous().token_type() == TokenType::Semicolon {
-                return;
-            }
-
             match self.peek().token_type() {
-                TokenType::Class
+                TokenType::LeftBrace => braces += 1,
+                TokenType::RightBrace if braces > 0 => braces -= 1,
+                TokenType::Semicolon if braces == 0 => {
+                    self.advance();
+
+                    return;
+                }
+                TokenType::RightBrace
+                | TokenType::Break
+                | TokenType::Class
+                | TokenType::Continue
                 | TokenType::For
                 | TokenType::Fun
                 | TokenType::If
+                | TokenType::Match
                 | TokenType::Print
                 | TokenType::Return
                 | TokenType::Var
-                | TokenType::While => {
+                | TokenType::While
+                    if braces == 0 =>
+                {
                     return;
                 }
                 _ => {}
             }
+
+            self.advance();
         }
+    }
 
-        self.advance();
+    fn nested<T>(&mut self, parse: impl FnOnce(&mut Self) -> Result<T>) -> Result<T> {
+        if self.depth >= self.max_depth {
+            return Err(Error::Parser(ParserError::TooDeep {
+                token: self.peek().clone(),
+                max_depth: self.max_depth,
+            }));
+        }
+
+        self.depth += 1;
+        let result = parse(self);
+        self.depth -= 1;
+
+        result
+    }
+
+    fn span_from(&self, start: usize) -> Span {
+        let first = self.tokens[start].span();
+
+        if self.current > start {
+            first.to(self.previous().span())
+        } else {
+            first
+        }
     }
 
     fn match_token_type(&mut self, token_types: &[TokenType]) -> bool {
@@ -217,6 +1256,10 @@ impl<'a> Parser<'a> {
         &self.tokens[self.current]
     }
 
+    fn peek_next(&self) -> Option<&Token> {
+        self.tokens.get(self.current + 1)
+    }
+
     fn previous(&self) -> &Token {
         &self.tokens[self.current - 1]
     }
@@ -0,0 +1,480 @@
+use std::{cell::Cell, collections::HashMap};
+
+use crate::{
+    ast::{Expr, FunctionDecl, Literal, MatchArm, Pattern, Stmt},
+    error::{Error, ResolverError, ResolverWarning},
+    scanner::token::Token,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FunctionType {
+    None,
+    Function,
+    Initializer,
+    Method,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ClassType {
+    None,
+    Class,
+    Subclass,
+}
+
+struct Binding {
+    name: Token,
+    defined: bool,
+}
+
+pub struct Resolver {
+    scopes: Vec<HashMap<String, Binding>>,
+    current_function: FunctionType,
+    current_class: ClassType,
+    loops: Vec<Option<String>>,
+    errors: Vec<Error>,
+    warnings: Vec<ResolverWarning>,
+}
+
+impl Resolver {
+    pub fn new() -> Self {
+        Self {
+            scopes: Vec::new(),
+            current_function: FunctionType::None,
+            current_class: ClassType::None,
+            loops: Vec::new(),
+            errors: Vec::new(),
+            warnings: Vec::new(),
+        }
+    }
+
+    pub fn resolve(&mut self, statements: &[Stmt]) -> Vec<Error> {
+        self.resolve_statements(statements);
+
+        std::mem::take(&mut self.errors)
+    }
+
+    pub fn warnings(&self) -> &[ResolverWarning] {
+        &self.warnings
+    }
+
+    fn resolve_statements(&mut self, statements: &[Stmt]) {
+        for statement in statements {
+            self.resolve_statement(statement);
+        }
+    }
+
+    fn resolve_statement(&mut self, stmt: &Stmt) {
+        match stmt {
+            Stmt::Expression(expr) | Stmt::Print { value: expr, .. } => {
+                self.resolve_expression(expr)
+            }
+            Stmt::Var { name, initializer } => {
+                self.declare(name);
+
+                if let Some(initializer) = initializer {
+                    self.resolve_expression(initializer);
+                }
+
+                self.define(name);
+            }
+            Stmt::Block(statements) => {
+                self.begin_scope();
+                self.resolve_statements(statements);
+                self.end_scope();
+            }
+            Stmt::If { .. } => {
+                let mut branch = Some(stmt);
+
+                while let Some(Stmt::If {
+                    condition,
+                    then_branch,
+                    else_branch,
+                }) = branch
+                {
+                    self.resolve_expression(condition);
+                    self.resolve_statement(then_branch);
+
+                    branch = else_branch.as_deref();
+                }
+
+                if let Some(else_branch) = branch {
+                    self.resolve_statement(else_branch);
+                }
+            }
+            Stmt::While {
+                label,
+                condition,
+                body,
+                increment,
+            } => {
+                self.resolve_expression(condition);
+
+                self.loops
+                    .push(label.as_ref().map(|label| label.identifier().into()));
+                self.resolve_statement(body);
+                self.loops.pop();
+
+                if let Some(increment) = increment {
+                    self.resolve_expression(increment);
+                }
+            }
+            Stmt::Break { keyword, label } | Stmt::Continue { keyword, label } => {
+                if self.loops.is_empty() {
+                    self.error(ResolverError::OutsideLoop {
+                        keyword: keyword.clone(),
+                    });
+                } else if let Some(label) = label
+                    && !self
+                        .loops
+                        .iter()
+                        .any(|name| name.as_deref() == Some(label.identifier()))
+                {
+                    self.error(ResolverError::UnknownLabel {
+                        label: label.clone(),
+                    });
+                }
+            }
+            Stmt::Function(declaration) => {
+                self.declare(&declaration.name);
+                self.define(&declaration.name);
+
+                self.resolve_function(declaration, FunctionType::Function);
+            }
+            Stmt::Class {
+                name,
+                superclass,
+                methods,
+            } => {
+                let enclosing_class = self.current_class;
+                self.current_class = ClassType::Class;
+
+                self.declare(name);
+                self.define(name);
+
+                if let Some(superclass) = superclass {
+                    if let Expr::Variable {
+                        name: superclass_name,
+                        ..
+                    } = superclass.as_ref()
+                        && superclass_name.identifier() == name.identifier()
+                    {
+                        self.error(ResolverError::InheritFromSelf {
+                            name: superclass_name.clone(),
+                        });
+                    }
+
+                    self.current_class = ClassType::Subclass;
+                    self.resolve_expression(superclass);
+
+                    self.begin_scope();
+
+                    self.define_implicit("super", name);
+                }
+
+                self.begin_scope();
+
+                self.define_implicit("this", name);
+
+                for method in methods {
+                    let function_type = if method.name.identifier() == "init" {
+                        FunctionType::Initializer
+                    } else {
+                        FunctionType::Method
+                    };
+
+                    self.resolve_function(method, function_type);
+                }
+
+                self.end_scope();
+
+                if superclass.is_some() {
+                    self.end_scope();
+                }
+
+                self.current_class = enclosing_class;
+            }
+            Stmt::Return { keyword, value } => {
+                if self.current_function == FunctionType::None {
+                    self.error(ResolverError::TopLevelReturn {
+                        keyword: keyword.clone(),
+                    });
+                }
+
+                if let Some(value) = value {
+                    if self.current_function == FunctionType::Initializer {
+                        self.error(ResolverError::ReturnFromInitializer {
+                            keyword: keyword.clone(),
+                        });
+                    }
+
+                    self.resolve_expression(value);
+                }
+            }
+            Stmt::Match {
+                keyword,
+                scrutinee,
+                arms,
+            } => {
+                self.resolve_expression(scrutinee);
+
+                for arm in arms {
+                    self.begin_scope();
+
+                    match &arm.pattern {
+                        Pattern::Binding(name) => {
+                            self.declare(name);
+                            self.define(name);
+                        }
+                        Pattern::Class { name, class, depth } => {
+                            self.resolve_local(class, depth);
+
+                            if let Some(name) = name {
+                                self.declare(name);
+                                self.define(name);
+                            }
+                        }
+                        Pattern::Wildcard | Pattern::Literal { .. } | Pattern::Range { .. } => {}
+                    }
+
+                    if let Some(guard) = &arm.guard {
+                        self.resolve_expression(guard);
+                    }
+
+                    self.resolve_statement(&arm.body);
+                    self.end_scope();
+                }
+
+                self.check_bool_match(keyword, arms);
+            }
+            Stmt::Error(_) => {}
+        }
+    }
+
+    fn check_bool_match(&mut self, keyword: &Token, arms: &[MatchArm]) {
+        let mut covered = [false; 2];
+
+        for arm in arms {
+            let Pattern::Literal {
+                value: Literal::Bool(value),
+                ..
+            } = arm.pattern
+            else {
+                return;
+            };
+
+            if arm.guard.is_none() {
+                covered[usize::from(value)] = true;
+            }
+        }
+
+        if let Some(missing) = [true, false]
+            .into_iter()
+            .find(|value| !covered[usize::from(*value)])
+            && !arms.is_empty()
+        {
+            self.warnings.push(ResolverWarning::NonExhaustiveMatch {
+                keyword: keyword.clone(),
+                missing,
+            });
+        }
+    }
+
+    fn resolve_function(&mut self, declaration: &FunctionDecl, function_type: FunctionType) {
+        let enclosing_function = self.current_function;
+        self.current_function = function_type;
+
+        let enclosing_loops = std::mem::take(&mut self.loops);
+
+        self.begin_scope();
+
+        for param in &declaration.params {
+            if let Some(default) = &param.default {
+                self.resolve_expression(default);
+            }
+
+            self.declare(&param.name);
+            self.define(&param.name);
+        }
+
+        if let Some(rest) = &declaration.rest {
+            self.declare(rest);
+            self.define(rest);
+        }
+
+        self.resolve_statements(&declaration.body);
+        self.end_scope();
+
+        self.current_function = enclosing_function;
+        self.loops = enclosing_loops;
+    }
+
+    fn resolve_expression(&mut self, expr: &Expr) {
+        match expr {
+            Expr::Literal { .. } | Expr::Error(_) => {}
+            Expr::Grouping(expr) => self.resolve_expression(expr),
+            Expr::Interpolation(parts) => {
+                for part in parts {
+                    self.resolve_expression(part);
+                }
+            }
+            Expr::Unary { right, .. } => self.resolve_expression(right),
+            Expr::Chain(expr) => self.resolve_expression(expr),
+            Expr::Lambda(declaration) => self.resolve_function(declaration, FunctionType::Function),
+            Expr::Comma(exprs) => {
+                for expr in exprs {
+                    self.resolve_expression(expr);
+                }
+            }
+            Expr::Conditional {
+                condition,
+                then_branch,
+                else_branch,
+            } => {
+                self.resolve_expression(condition);
+                self.resolve_expression(then_branch);
+                self.resolve_expression(else_branch);
+            }
+            Expr::Binary { .. }
+            | Expr::Logical { .. }
+            | Expr::Coalesce { .. }
+            | Expr::Call { .. }
+            | Expr::Get { .. }
+            | Expr::OptionalGet { .. } => {
+                let (operand, links) = expr.unchain();
+
+                self.resolve_expression(operand);
+
+                for link in links {
+                    match link {
+                        Expr::Binary { right, .. }
+                        | Expr::Logical { right, .. }
+                        | Expr::Coalesce { right, .. } => self.resolve_expression(right),
+                        Expr::Call { arguments, .. } => {
+                            for argument in arguments {
+                                self.resolve_expression(argument.value());
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            Expr::Variable { name, depth } => {
+                if let Some(scope) = self.scopes.last()
+                    && scope
+                        .get(name.identifier())
+                        .is_some_and(|binding| !binding.defined)
+                {
+                    self.error(ResolverError::ReadInOwnInitializer { name: name.clone() });
+                }
+
+                self.resolve_local(name, depth);
+            }
+            Expr::Assign { name, value, depth }
+            | Expr::CompoundAssign {
+                name, value, depth, ..
+            } => {
+                self.resolve_expression(value);
+                self.resolve_local(name, depth);
+            }
+            Expr::Set { object, value, .. } | Expr::CompoundSet { object, value, .. } => {
+                self.resolve_expression(value);
+                self.resolve_expression(object);
+            }
+            Expr::This { keyword, depth } => {
+                if self.current_class == ClassType::None {
+                    self.error(ResolverError::ThisOutsideClass {
+                        keyword: keyword.clone(),
+                    });
+
+                    return;
+                }
+
+                self.resolve_local(keyword, depth);
+            }
+            Expr::Super { keyword, depth, .. } => {
+                match self.current_class {
+                    ClassType::None => {
+                        self.error(ResolverError::SuperOutsideClass {
+                            keyword: keyword.clone(),
+                        });
+
+                        return;
+                    }
+                    ClassType::Class => {
+                        self.error(ResolverError::SuperWithoutSuperclass {
+                            keyword: keyword.clone(),
+                        });
+
+                        return;
+                    }
+                    ClassType::Subclass => {}
+                }
+
+                self.resolve_local(keyword, depth);
+            }
+        }
+    }
+
+    fn resolve_local(&mut self, name: &Token, depth: &Cell<Option<usize>>) {
+        if let Some(distance) = self
+            .scopes
+            .iter()
+            .rev()
+            .position(|scope| scope.contains_key(name.identifier()))
+        {
+            depth.set(Some(distance));
+        }
+    }
+
+    fn begin_scope(&mut self) {
+        self.scopes.push(HashMap::new());
+    }
+
+    fn end_scope(&mut self) {
+        self.scopes.pop();
+    }
+
+    fn declare(&mut self, name: &Token) {
+        let Some(scope) = self.scopes.last_mut() else {
+            return;
+        };
+
+        let binding = Binding {
+            name: name.clone(),
+            defined: false,
+        };
+
+        if let Some(previous) = scope.insert(name.identifier().into(), binding) {
+            self.error(ResolverError::AlreadyDeclared {
+                name: name.clone(),
+                previous: previous.name.span(),
+            });
+        }
+    }
+
+    fn define(&mut self, name: &Token) {
+        if let Some(binding) = self
+            .scopes
+            .last_mut()
+            .and_then(|scope| scope.get_mut(name.identifier()))
+        {
+            binding.defined = true;
+        }
+    }
+
+    fn define_implicit(&mut self, identifier: &str, name: &Token) {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(
+                identifier.into(),
+                Binding {
+                    name: name.clone(),
+                    defined: true,
+                },
+            );
+        }
+    }
+
+    fn error(&mut self, error: ResolverError) {
+        self.errors.push(Error::Resolver(error));
+    }
+}
@@ -2,26 +2,41 @@ pub mod token;
 
 use std::{iter::Peekable, str::Chars};
 
-use token::{Token, TokenType};
+use token::{Span, Token, TokenType};
 
 use crate::{
     error::{Error, SyntaxError},
+    number::{BigInt, Number},
     result::Result,
 };
 
 #[derive(Debug)]
 pub struct Scanner<'a> {
+    source: &'a str,
     chars: Peekable<Chars<'a>>,
     tokens: Vec<Token>,
+    start: usize,
+    start_line: usize,
+    start_column: usize,
+    current: usize,
     line: usize,
+    column: usize,
+    interpolations: Vec<(usize, Span)>,
 }
 
 impl<'a> Scanner<'a> {
     pub fn new(source: &'a str) -> Self {
         Self {
+            source,
             chars: source.chars().peekable(),
             tokens: vec![],
-            line: 0,
+            start: 0,
+            start_line: 1,
+            start_column: 1,
+            current: 0,
+            line: 1,
+            column: 1,
+            interpolations: Vec::new(),
         }
     }
 
@@ -29,11 +44,23 @@ impl<'a> Scanner<'a> {
         let mut errors = Vec::new();
 
         while self.chars.peek().is_some() {
+            self.start = self.current;
+            self.start_line = self.line;
+            self.start_column = self.column;
+
             if let Err(e) = self.scan_token() {
                 errors.push(e);
             }
         }
 
+        if let Some((_, span)) = self.interpolations.pop() {
+            errors.push(Error::Syntax(SyntaxError::UnterminatedString { span }));
+        }
+
+        self.start = self.current;
+        self.start_line = self.line;
+        self.start_column = self.column;
+
         self.add_token(TokenType::Eof);
 
         errors
@@ -44,18 +71,80 @@ impl<'a> Scanner<'a> {
     }
 
     fn scan_token(&mut self) -> Result<()> {
-        if let Some(c) = self.chars.next() {
+        if let Some(c) = self.advance() {
             match c {
                 '(' => self.add_token(TokenType::LeftParen),
                 ')' => self.add_token(TokenType::RightParen),
-                '{' => self.add_token(TokenType::LeftBrace),
-                '}' => self.add_token(TokenType::RightBrace),
+                '{' => {
+                    if let Some((braces, _)) = self.interpolations.last_mut() {
+                        *braces += 1;
+                    }
+
+                    self.add_token(TokenType::LeftBrace);
+                }
+                '}' => match self.interpolations.last_mut() {
+                    Some((0, _)) => {
+                        self.interpolations.pop();
+
+                        let span = self.span();
+                        let empty = matches!(
+                            self.tokens.last().map(Token::token_type),
+                            Some(TokenType::Interpolation(_))
+                        );
+
+                        self.string(true)?;
+
+                        if empty {
+                            return Err(Error::Syntax(SyntaxError::EmptyInterpolation { span }));
+                        }
+                    }
+                    Some((braces, _)) => {
+                        *braces -= 1;
+                        self.add_token(TokenType::RightBrace);
+                    }
+                    None => self.add_token(TokenType::RightBrace),
+                },
                 ',' => self.add_token(TokenType::Comma),
-                '.' => self.add_token(TokenType::Dot),
-                '-' => self.add_token(TokenType::Minus),
-                '+' => self.add_token(TokenType::Plus),
+                '.' => {
+                    let token_type = if !self.is_match('.') {
+                        TokenType::Dot
+                    } else if self.is_match('=') {
+                        TokenType::DotDotEqual
+                    } else if self.is_match('.') {
+                        TokenType::Ellipsis
+                    } else {
+                        TokenType::DotDot
+                    };
+
+                    self.add_token(token_type);
+                }
+                '-' => self.operator(TokenType::Minus, TokenType::MinusEqual),
+                '+' => self.operator(TokenType::Plus, TokenType::PlusEqual),
                 ';' => self.add_token(TokenType::Semicolon),
-                '*' => self.add_token(TokenType::Star),
+                '?' => {
+                    let token_type = if self.is_match('.') {
+                        TokenType::QuestionDot
+                    } else if self.is_match('?') {
+                        TokenType::QuestionQuestion
+                    } else {
+                        TokenType::Question
+                    };
+
+                    self.add_token(token_type);
+                }
+                ':' => self.add_token(TokenType::Colon),
+                '*' => {
+                    if self.is_match('*') {
+                        self.operator(TokenType::StarStar, TokenType::StarStarEqual);
+                    } else {
+                        self.operator(TokenType::Star, TokenType::StarEqual);
+                    }
+                }
+                '%' => self.operator(TokenType::Percent, TokenType::PercentEqual),
+                '&' => self.operator(TokenType::Ampersand, TokenType::AmpersandEqual),
+                '|' => self.operator(TokenType::Pipe, TokenType::PipeEqual),
+                '^' => self.operator(TokenType::Caret, TokenType::CaretEqual),
+                '~' => self.add_token(TokenType::Tilde),
                 '!' => {
                     let token_type = if self.is_match('=') {
                         TokenType::BangEqual
@@ -68,6 +157,8 @@ impl<'a> Scanner<'a> {
                 '=' => {
                     let token_type = if self.is_match('=') {
                         TokenType::EqualEqual
+                    } else if self.is_match('>') {
+                        TokenType::FatArrow
                     } else {
                         TokenType::Equal
                     };
@@ -75,26 +166,22 @@ impl<'a> Scanner<'a> {
                     self.add_token(token_type);
                 }
                 '<' => {
-                    let token_type = if self.is_match('=') {
-                        TokenType::LessEqual
+                    if self.is_match('<') {
+                        self.operator(TokenType::LessLess, TokenType::LessLessEqual);
                     } else {
-                        TokenType::Less
-                    };
-
-                    self.add_token(token_type);
+                        self.operator(TokenType::Less, TokenType::LessEqual);
+                    }
                 }
                 '>' => {
-                    let token_type = if self.is_match('=') {
-                        TokenType::GreaterEqual
+                    if self.is_match('>') {
+                        self.operator(TokenType::GreaterGreater, TokenType::GreaterGreaterEqual);
                     } else {
-                        TokenType::Greater
-                    };
-
-                    self.add_token(token_type);
+                        self.operator(TokenType::Greater, TokenType::GreaterEqual);
+                    }
                 }
                 '/' => {
                     if self.is_match('/') {
-                        for c in self.chars.by_ref() {
+                        while let Some(c) = self.advance() {
                             if c == '\n' {
                                 break;
                             }
@@ -102,17 +189,17 @@ impl<'a> Scanner<'a> {
                     } else if self.is_match('*') {
                         self.multiline_comment()?;
                     } else {
-                        self.add_token(TokenType::Slash);
+                        self.operator(TokenType::Slash, TokenType::SlashEqual);
                     }
                 }
-                '"' => self.string()?,
+                '"' => self.string(false)?,
+                'r' if self.is_raw_string() => self.raw_string()?,
                 c if c.is_ascii_digit() => self.number(c)?,
                 c if c.is_alphabetic() || c == '_' => self.identifier(c),
-                ' ' | '\r' | '\t' => {}
-                '\n' => self.line += 1,
+                ' ' | '\r' | '\t' | '\n' => {}
                 _ => {
                     return Err(Error::Syntax(SyntaxError::UnexpectedCharacter {
-                        line: self.line,
+                        span: self.span(),
                         character: c,
                     }));
                 }
@@ -122,103 +209,295 @@ impl<'a> Scanner<'a> {
         Ok(())
     }
 
+    fn operator(&mut self, token_type: TokenType, with_equal: TokenType) {
+        if self.is_match('=') {
+            self.add_token(with_equal);
+        } else {
+            self.add_token(token_type);
+        }
+    }
+
     fn add_token(&mut self, token_type: TokenType) {
-        self.tokens.push(Token::new(token_type, self.line))
+        let lexeme = self.source[self.start..self.current].to_string();
+
+        self.tokens
+            .push(Token::new(token_type, lexeme, self.span()))
+    }
+
+    fn span(&self) -> Span {
+        Span::new(self.start, self.current, self.start_line, self.start_column)
+    }
+
+    fn advance(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+
+        self.current += c.len_utf8();
+
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+
+        Some(c)
+    }
+
+    fn peek_next(&self) -> Option<char> {
+        let mut chars = self.chars.clone();
+
+        chars.next();
+        chars.next()
     }
 
     fn is_match(&mut self, expected: char) -> bool {
-        if let Some(c) = self.chars.peek() {
-            if *c == expected {
-                self.chars.next();
+        if let Some(c) = self.chars.peek()
+            && *c == expected
+        {
+            self.advance();
 
-                return true;
-            }
+            return true;
         }
 
         false
     }
 
-    fn string(&mut self) -> Result<()> {
+    fn string(&mut self, continued: bool) -> Result<()> {
         let mut buf = String::new();
+        let mut error = None;
 
-        while let Some(&c) = self.chars.peek() {
-            if c == '"' {
-                break;
-            }
+        loop {
+            match self.advance() {
+                None => {
+                    return Err(Error::Syntax(SyntaxError::UnterminatedString {
+                        span: self.span(),
+                    }));
+                }
+                Some('"') => break,
+                Some('\\') => match self.escape() {
+                    Ok(c) => buf.push(c),
+                    Err(e) => {
+                        error.get_or_insert(e);
+                    }
+                },
+                Some('$') if self.is_match('{') => {
+                    self.interpolations.push((0, self.span()));
+                    self.add_token(TokenType::Interpolation(buf));
 
-            if c == '\n' {
-                self.line += 1;
+                    return error.map_or(Ok(()), Err);
+                }
+                Some(c) => buf.push(c),
             }
+        }
 
-            buf.push(c);
-            self.chars.next();
+        if let Some(e) = error {
+            return Err(e);
         }
 
-        if let Some(c) = self.chars.peek() {
-            if *c != '"' {
-                return Err(Error::Syntax(SyntaxError::UnterminatedString {
-                    line: self.line,
-                }));
+        if continued {
+            self.add_token(TokenType::InterpolationEnd(buf));
+        } else {
+            self.add_token(TokenType::String(buf));
+        }
+
+        Ok(())
+    }
+
+    fn is_raw_string(&self) -> bool {
+        self.source[self.current..]
+            .trim_start_matches('#')
+            .starts_with('"')
+    }
+
+    fn raw_string(&mut self) -> Result<()> {
+        let mut hashes = 0;
+
+        while self.is_match('#') {
+            hashes += 1;
+        }
+
+        self.advance();
+
+        let mut buf = String::new();
+
+        loop {
+            match self.advance() {
+                None => {
+                    return Err(Error::Syntax(SyntaxError::UnterminatedString {
+                        span: self.span(),
+                    }));
+                }
+                Some('"') if self.closes_raw_string(hashes) => {
+                    for _ in 0..hashes {
+                        self.advance();
+                    }
+
+                    break;
+                }
+                Some(c) => buf.push(c),
             }
         }
 
-        self.chars.next();
         self.add_token(TokenType::String(buf));
 
         Ok(())
     }
 
-    fn number(&mut self, start_char: char) -> Result<()> {
-        let mut buf = start_char.to_string();
+    fn closes_raw_string(&self, hashes: usize) -> bool {
+        self.source[self.current..]
+            .bytes()
+            .take_while(|&byte| byte == b'#')
+            .count()
+            >= hashes
+    }
+
+    fn escape(&mut self) -> Result<char> {
+        let start = self.current - 1;
+        let line = self.line;
+        let column = self.column - 1;
+
+        let c = match self.advance() {
+            Some('n') => Some('\n'),
+            Some('t') => Some('\t'),
+            Some('r') => Some('\r'),
+            Some('0') => Some('\0'),
+            Some(c @ ('\\' | '"' | '\'' | '$')) => Some(c),
+            Some('u') => self.unicode_escape(),
+            _ => None,
+        };
+
+        c.ok_or_else(|| {
+            Error::Syntax(SyntaxError::InvalidEscape {
+                span: Span::new(start, self.current, line, column),
+                sequence: self.source[start..self.current].into(),
+            })
+        })
+    }
+
+    fn unicode_escape(&mut self) -> Option<char> {
+        if !self.is_match('{') {
+            return None;
+        }
+
+        let mut digits = String::new();
 
         while let Some(&c) = self.chars.peek() {
-            if !c.is_ascii_digit() {
+            if !c.is_ascii_hexdigit() {
                 break;
             }
 
-            buf.push(c);
-            self.chars.next();
+            digits.push(c);
+            self.advance();
         }
 
-        if let Some(c) = self.chars.peek() {
-            if *c == '.' {
-                self.chars.next();
+        if !self.is_match('}') || digits.is_empty() || digits.len() > 6 {
+            return None;
+        }
 
-                if let Some(c) = self.chars.peek() {
-                    if c.is_ascii_digit() {
-                        buf.push('.');
+        u32::from_str_radix(&digits, 16)
+            .ok()
+            .and_then(char::from_u32)
+    }
 
-                        while let Some(&c) = self.chars.peek() {
-                            if !c.is_ascii_digit() {
-                                break;
-                            }
+    fn number(&mut self, start_char: char) -> Result<()> {
+        let radix = match (start_char, self.chars.peek()) {
+            ('0', Some('x' | 'X')) => 16,
+            ('0', Some('o' | 'O')) => 8,
+            ('0', Some('b' | 'B')) => 2,
+            _ => 10,
+        };
 
-                            buf.push(c);
-                            self.chars.next();
-                        }
+        let mut buf = String::new();
+        let mut float = false;
 
-                        self.add_token(TokenType::Number(buf.parse::<f64>().or(Err(
-                            Error::Syntax(SyntaxError::InvalidNumber { line: self.line }),
-                        ))?));
+        let (valid, num) = if radix == 10 {
+            buf.push(start_char);
 
-                        return Ok(());
-                    }
+            let mut valid = self.digits(10, &mut buf);
+
+            if self.chars.peek() == Some(&'.')
+                && self.peek_next().is_some_and(|c| c.is_ascii_digit())
+            {
+                buf.push('.');
+                self.advance();
+
+                float = true;
+                valid &= self.digits(10, &mut buf);
+            }
+
+            if let Some(&e @ ('e' | 'E')) = self.chars.peek() {
+                buf.push(e);
+                self.advance();
+
+                float = true;
+
+                if let Some(&sign @ ('+' | '-')) = self.chars.peek() {
+                    buf.push(sign);
+                    self.advance();
                 }
 
-                self.add_token(TokenType::Number(buf.parse::<f64>().or(Err(
-                    Error::Syntax(SyntaxError::InvalidNumber { line: self.line }),
-                ))?));
-                self.add_token(TokenType::Dot);
+                let mantissa = buf.len();
 
-                return Ok(());
+                valid &= self.digits(10, &mut buf) && buf.len() > mantissa;
             }
+
+            let num = if float {
+                buf.parse().ok().map(Number::Float)
+            } else {
+                BigInt::from_str_radix(&buf, 10).map(Number::from)
+            };
+
+            (valid, num)
+        } else {
+            self.advance();
+
+            let valid = self.digits(radix, &mut buf) && !buf.is_empty();
+
+            (valid, BigInt::from_str_radix(&buf, radix).map(Number::from))
+        };
+
+        let mut valid = valid;
+
+        while self
+            .chars
+            .peek()
+            .is_some_and(|c| c.is_alphanumeric() || *c == '_')
+        {
+            valid = false;
+            self.advance();
         }
 
-        self.add_token(TokenType::Number(buf.parse::<f64>().or(Err(
-            Error::Syntax(SyntaxError::InvalidNumber { line: self.line }),
-        ))?));
+        match num.filter(|_| valid) {
+            Some(num) => {
+                self.add_token(TokenType::Number(num));
 
-        Ok(())
+                Ok(())
+            }
+            None => Err(Error::Syntax(SyntaxError::InvalidNumber {
+                span: self.span(),
+                lexeme: self.source[self.start..self.current].into(),
+            })),
+        }
+    }
+
+    fn digits(&mut self, radix: u32, buf: &mut String) -> bool {
+        let mut valid = true;
+        let mut previous = buf.chars().last();
+
+        while let Some(&c) = self.chars.peek() {
+            if c == '_' {
+                valid &= previous.is_some_and(|p| p.is_digit(radix));
+            } else if c.is_digit(radix) {
+                buf.push(c);
+            } else {
+                break;
+            }
+
+            previous = Some(c);
+            self.advance();
+        }
+
+        valid && previous != Some('_')
     }
 
     fn identifier(&mut self, start_char: char) {
@@ -230,17 +509,20 @@ impl<'a> Scanner<'a> {
             }
 
             buf.push(c);
-            self.chars.next();
+            self.advance();
         }
 
         self.add_token(match buf.as_str() {
             "and" => TokenType::And,
+            "break" => TokenType::Break,
             "class" => TokenType::Class,
+            "continue" => TokenType::Continue,
             "else" => TokenType::Else,
             "false" => TokenType::False,
             "for" => TokenType::For,
             "fun" => TokenType::Fun,
             "if" => TokenType::If,
+            "match" => TokenType::Match,
             "nil" => TokenType::Nil,
             "or" => TokenType::Or,
             "print" => TokenType::Print,
@@ -257,22 +539,18 @@ impl<'a> Scanner<'a> {
     fn multiline_comment(&mut self) -> Result<()> {
         let mut expected_end = false;
 
-        while let Some(c) = self.chars.next() {
+        while let Some(c) = self.advance() {
             if c == '*' && self.is_match('/') {
                 expected_end = true;
                 break;
             }
-
-            if c == '\n' {
-                self.line += 1;
-            }
         }
 
         if expected_end {
             Ok(())
         } else {
             Err(Error::Syntax(SyntaxError::UnterminatedMultilineComment {
-                line: self.line,
+                span: self.span(),
             }))
         }
     }
@@ -1,26 +1,44 @@
+mod span;
 mod token_type;
 
 use std::fmt::Display;
 
+pub use span::Span;
 pub use token_type::TokenType;
 
 #[derive(Debug, Clone)]
 pub struct Token {
     token_type: TokenType,
-    line: usize,
+    lexeme: String,
+    span: Span,
 }
 
 impl Token {
-    pub fn new(token_type: TokenType, line: usize) -> Self {
-        Self { token_type, line }
+    pub fn new(token_type: TokenType, lexeme: String, span: Span) -> Self {
+        Self {
+            token_type,
+            lexeme,
+            span,
+        }
     }
 
     pub fn token_type(&self) -> &TokenType {
         &self.token_type
     }
 
-    pub fn line(&self) -> usize {
-        self.line
+    pub fn lexeme(&self) -> &str {
+        &self.lexeme
+    }
+
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    pub fn identifier(&self) -> &str {
+        match &self.token_type {
+            TokenType::Identifier(identifier) => identifier,
+            _ => &self.lexeme,
+        }
     }
 }
 
@@ -1,5 +1,7 @@
 use std::fmt::Display;
 
+use crate::number::Number;
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum TokenType {
     LeftParen,
@@ -8,29 +10,61 @@ pub enum TokenType {
     RightBrace,
     Comma,
     Dot,
+    DotDot,
+    DotDotEqual,
+    Ellipsis,
     Minus,
     Plus,
     Semicolon,
     Slash,
     Star,
+    Percent,
+    Question,
+    QuestionDot,
+    QuestionQuestion,
+    Colon,
     Equal,
     EqualEqual,
+    FatArrow,
     Bang,
     BangEqual,
     Greater,
     GreaterEqual,
     Less,
     LessEqual,
+    PlusEqual,
+    MinusEqual,
+    StarEqual,
+    SlashEqual,
+    PercentEqual,
+    StarStar,
+    StarStarEqual,
+    Ampersand,
+    AmpersandEqual,
+    Pipe,
+    PipeEqual,
+    Caret,
+    CaretEqual,
+    Tilde,
+    LessLess,
+    LessLessEqual,
+    GreaterGreater,
+    GreaterGreaterEqual,
     Identifier(String),
     String(String),
-    Number(f64),
+    Interpolation(String),
+    InterpolationEnd(String),
+    Number(Number),
     And,
+    Break,
     Class,
+    Continue,
     Else,
     False,
     Fun,
     For,
     If,
+    Match,
     Nil,
     Or,
     Print,
@@ -43,6 +77,25 @@ pub enum TokenType {
     Eof,
 }
 
+impl TokenType {
+    pub fn compound_operator(&self) -> Option<Self> {
+        Some(match self {
+            Self::PlusEqual => Self::Plus,
+            Self::MinusEqual => Self::Minus,
+            Self::StarEqual => Self::Star,
+            Self::SlashEqual => Self::Slash,
+            Self::PercentEqual => Self::Percent,
+            Self::StarStarEqual => Self::StarStar,
+            Self::AmpersandEqual => Self::Ampersand,
+            Self::PipeEqual => Self::Pipe,
+            Self::CaretEqual => Self::Caret,
+            Self::LessLessEqual => Self::LessLess,
+            Self::GreaterGreaterEqual => Self::GreaterGreater,
+            _ => return None,
+        })
+    }
+}
+
 impl Display for TokenType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -52,29 +105,61 @@ impl Display for TokenType {
             Self::RightBrace => write!(f, "RightBrace"),
             Self::Comma => write!(f, "Comma"),
             Self::Dot => write!(f, "Dot"),
+            Self::DotDot => write!(f, "DotDot"),
+            Self::DotDotEqual => write!(f, "DotDotEqual"),
+            Self::Ellipsis => write!(f, "Ellipsis"),
             Self::Minus => write!(f, "Minus"),
             Self::Plus => write!(f, "Plus"),
             Self::Semicolon => write!(f, "Semicolon"),
             Self::Slash => write!(f, "Slash"),
             Self::Star => write!(f, "Star"),
+            Self::Percent => write!(f, "Percent"),
+            Self::Question => write!(f, "Question"),
+            Self::QuestionDot => write!(f, "QuestionDot"),
+            Self::QuestionQuestion => write!(f, "QuestionQuestion"),
+            Self::Colon => write!(f, "Colon"),
             Self::Equal => write!(f, "Equal"),
             Self::EqualEqual => write!(f, "EqualEqual"),
+            Self::FatArrow => write!(f, "FatArrow"),
             Self::Bang => write!(f, "Bang"),
             Self::BangEqual => write!(f, "BangEqual"),
             Self::Greater => write!(f, "Greater"),
             Self::GreaterEqual => write!(f, "GreaterEqual"),
             Self::Less => write!(f, "Less"),
             Self::LessEqual => write!(f, "LessEqual"),
+            Self::PlusEqual => write!(f, "PlusEqual"),
+            Self::MinusEqual => write!(f, "MinusEqual"),
+            Self::StarEqual => write!(f, "StarEqual"),
+            Self::SlashEqual => write!(f, "SlashEqual"),
+            Self::PercentEqual => write!(f, "PercentEqual"),
+            Self::StarStar => write!(f, "StarStar"),
+            Self::StarStarEqual => write!(f, "StarStarEqual"),
+            Self::Ampersand => write!(f, "Ampersand"),
+            Self::AmpersandEqual => write!(f, "AmpersandEqual"),
+            Self::Pipe => write!(f, "Pipe"),
+            Self::PipeEqual => write!(f, "PipeEqual"),
+            Self::Caret => write!(f, "Caret"),
+            Self::CaretEqual => write!(f, "CaretEqual"),
+            Self::Tilde => write!(f, "Tilde"),
+            Self::LessLess => write!(f, "LessLess"),
+            Self::LessLessEqual => write!(f, "LessLessEqual"),
+            Self::GreaterGreater => write!(f, "GreaterGreater"),
+            Self::GreaterGreaterEqual => write!(f, "GreaterGreaterEqual"),
             Self::Identifier(identifier) => write!(f, "Identifier({identifier})"),
             Self::String(s) => write!(f, "String(\"{s}\")"),
+            Self::Interpolation(s) => write!(f, "Interpolation(\"{s}\")"),
+            Self::InterpolationEnd(s) => write!(f, "InterpolationEnd(\"{s}\")"),
             Self::Number(num) => write!(f, "Number({num})"),
             Self::And => write!(f, "And"),
+            Self::Break => write!(f, "Break"),
             Self::Class => write!(f, "Class"),
+            Self::Continue => write!(f, "Continue"),
             Self::Else => write!(f, "Else"),
             Self::False => write!(f, "False"),
             Self::Fun => write!(f, "Function"),
             Self::For => write!(f, "For"),
             Self::If => write!(f, "If"),
+            Self::Match => write!(f, "Match"),
             Self::Nil => write!(f, "Nil"),
             Self::Or => write!(f, "Or"),
             Self::Print => write!(f, "Print"),
@@ -0,0 +1,807 @@
+mod native;
+mod object;
+mod value;
+
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+pub use object::{
+    BoundMethod, Class, Closure, Function, Instance, NativeFunction, Parameter, Upvalue,
+};
+pub use value::Value;
+
+use crate::{
+    chunk::{ArgumentKind, OpCode},
+    error::{ArithmeticError, Error, VmError},
+    number::Number,
+    result::Result,
+    scanner::token::Span,
+};
+
+const FRAMES_MAX: usize = 1 << 16;
+
+struct CallFrame {
+    closure: Rc<Closure>,
+    ip: usize,
+    start: usize,
+    slots: usize,
+    missing: Vec<u8>,
+}
+
+pub struct Vm {
+    stack: Vec<Value>,
+    frames: Vec<CallFrame>,
+    globals: HashMap<String, Value>,
+    open_upvalues: Vec<Rc<RefCell<Upvalue>>>,
+}
+
+impl Vm {
+    pub fn new() -> Self {
+        let globals = native::natives()
+            .into_iter()
+            .map(|native| (native.name.to_string(), Value::Native(Rc::new(native))))
+            .collect();
+
+        Self {
+            stack: Vec::new(),
+            frames: Vec::new(),
+            globals,
+            open_upvalues: Vec::new(),
+        }
+    }
+
+    pub fn interpret(&mut self, function: Rc<Function>) -> Result<()> {
+        let closure = Rc::new(Closure {
+            function,
+            upvalues: Vec::new(),
+        });
+
+        self.stack.push(Value::Closure(closure.clone()));
+
+        let result = self.call(closure, 0, Vec::new()).and_then(|_| self.run());
+
+        if result.is_err() {
+            self.stack.clear();
+            self.frames.clear();
+            self.open_upvalues.clear();
+        }
+
+        result
+    }
+
+    fn run(&mut self) -> Result<()> {
+        loop {
+            let frame = self.frame_mut();
+            frame.start = frame.ip;
+
+            let byte = self.read_byte();
+
+            let op_code = OpCode::try_from(byte)
+                .map_err(|byte| self.error(|span| VmError::InvalidBytecode { byte, span }))?;
+
+            match op_code {
+                OpCode::Constant | OpCode::ConstantLong => {
+                    let constant = self.read_constant(op_code == OpCode::ConstantLong);
+                    self.push(constant);
+                }
+                OpCode::Nil => self.push(Value::Nil),
+                OpCode::True => self.push(Value::Bool(true)),
+                OpCode::False => self.push(Value::Bool(false)),
+                OpCode::Pop => {
+                    self.pop();
+                }
+                OpCode::Dup => self.push(self.peek(0).clone()),
+                OpCode::GetLocal => {
+                    let slot = self.frame().slots + self.read_byte() as usize;
+                    self.push(self.stack[slot].clone());
+                }
+                OpCode::SetLocal => {
+                    let slot = self.frame().slots + self.read_byte() as usize;
+                    self.stack[slot] = self.peek(0).clone();
+                }
+                OpCode::GetGlobal | OpCode::GetGlobalLong => {
+                    let name = self.read_string(op_code == OpCode::GetGlobalLong);
+
+                    match self.globals.get(name.as_ref()) {
+                        Some(value) => self.push(value.clone()),
+                        None => {
+                            return Err(self.error(|span| VmError::UndefinedVariable {
+                                name: name.to_string(),
+                                span,
+                            }));
+                        }
+                    }
+                }
+                OpCode::DefineGlobal | OpCode::DefineGlobalLong => {
+                    let name = self.read_string(op_code == OpCode::DefineGlobalLong);
+                    let value = self.pop();
+
+                    self.globals.insert(name.to_string(), value);
+                }
+                OpCode::SetGlobal | OpCode::SetGlobalLong => {
+                    let name = self.read_string(op_code == OpCode::SetGlobalLong);
+                    let value = self.peek(0).clone();
+
+                    match self.globals.get_mut(name.as_ref()) {
+                        Some(slot) => *slot = value,
+                        None => {
+                            return Err(self.error(|span| VmError::UndefinedVariable {
+                                name: name.to_string(),
+                                span,
+                            }));
+                        }
+                    }
+                }
+                OpCode::GetUpvalue => {
+                    let index = self.read_byte() as usize;
+                    let upvalue = self.frame().closure.upvalues[index].clone();
+
+                    let value = match &*upvalue.borrow() {
+                        Upvalue::Open(slot) => self.stack[*slot].clone(),
+                        Upvalue::Closed(value) => value.clone(),
+                    };
+
+                    self.push(value);
+                }
+                OpCode::SetUpvalue => {
+                    let index = self.read_byte() as usize;
+                    let upvalue = self.frame().closure.upvalues[index].clone();
+                    let value = self.peek(0).clone();
+
+                    match &mut *upvalue.borrow_mut() {
+                        Upvalue::Open(slot) => self.stack[*slot] = value,
+                        Upvalue::Closed(closed) => *closed = value,
+                    }
+                }
+                OpCode::GetProperty | OpCode::GetPropertyLong => {
+                    let name = self.read_string(op_code == OpCode::GetPropertyLong);
+
+                    let Value::Instance(instance) = self.peek(0).clone() else {
+                        return Err(self.error(|span| VmError::OnlyInstancesHaveProperties {
+                            name: name.to_string(),
+                            span,
+                        }));
+                    };
+
+                    let field = instance.borrow().fields.get(name.as_ref()).cloned();
+
+                    match field {
+                        Some(value) => {
+                            self.pop();
+                            self.push(value);
+                        }
+                        None => {
+                            let class = instance.borrow().class.clone();
+                            self.bind_method(&class, &name)?;
+                        }
+                    }
+                }
+                OpCode::SetProperty | OpCode::SetPropertyLong => {
+                    let name = self.read_string(op_code == OpCode::SetPropertyLong);
+
+                    let Value::Instance(instance) = self.peek(1).clone() else {
+                        return Err(self.error(|span| VmError::OnlyInstancesHaveProperties {
+                            name: name.to_string(),
+                            span,
+                        }));
+                    };
+
+                    let value = self.pop();
+
+                    instance
+                        .borrow_mut()
+                        .fields
+                        .insert(name.to_string(), value.clone());
+
+                    self.pop();
+                    self.push(value);
+                }
+                OpCode::GetSuper | OpCode::GetSuperLong => {
+                    let name = self.read_string(op_code == OpCode::GetSuperLong);
+
+                    let Value::Class(superclass) = self.pop() else {
+                        return Err(self.error(|span| VmError::SuperclassMustBeClass {
+                            name: "super".into(),
+                            span,
+                        }));
+                    };
+
+                    self.bind_method(&superclass, &name)?;
+                }
+                OpCode::Equal => {
+                    let b = self.pop();
+                    let a = self.pop();
+
+                    self.push(Value::Bool(a.is_equal(&b)));
+                }
+                OpCode::Greater => self.binary_op(">", |a, b| Ok(Value::Bool(a > b)))?,
+                OpCode::GreaterEqual => self.binary_op(">=", |a, b| Ok(Value::Bool(a >= b)))?,
+                OpCode::Less => self.binary_op("<", |a, b| Ok(Value::Bool(a < b)))?,
+                OpCode::LessEqual => self.binary_op("<=", |a, b| Ok(Value::Bool(a <= b)))?,
+                OpCode::InRange => {
+                    let inclusive = self.read_byte() != 0;
+
+                    let in_range = match (self.peek(2), self.peek(1), self.peek(0)) {
+                        (Value::Number(num), Value::Number(start), Value::Number(end)) => {
+                            let below_end = if inclusive { num <= end } else { num < end };
+
+                            start <= num && below_end
+                        }
+                        _ => false,
+                    };
+
+                    self.pop();
+                    self.pop();
+                    self.pop();
+                    self.push(Value::Bool(in_range));
+                }
+                OpCode::IsInstance | OpCode::IsInstanceLong => {
+                    let name = self.read_string(op_code == OpCode::IsInstanceLong);
+
+                    let Value::Class(class) = self.peek(0) else {
+                        return Err(self.error(|span| VmError::PatternMustBeClass {
+                            name: name.to_string(),
+                            span,
+                        }));
+                    };
+
+                    let is_instance = match self.peek(1) {
+                        Value::Instance(instance) => {
+                            instance.borrow().class.borrow().inherits_from(class)
+                        }
+                        _ => false,
+                    };
+
+                    self.pop();
+                    self.pop();
+                    self.push(Value::Bool(is_instance));
+                }
+                OpCode::Add => match (self.peek(1), self.peek(0)) {
+                    (Value::String(a), Value::String(b)) => {
+                        let result: Rc<str> = format!("{a}{b}").into();
+
+                        self.pop();
+                        self.pop();
+                        self.push(Value::String(result));
+                    }
+                    (Value::Number(_), Value::Number(_)) => {
+                        self.binary_op("+", |a, b| a.checked_add(b).map(Value::Number))?
+                    }
+                    _ => {
+                        return Err(self.error(|span| VmError::OperandsMustBeNumbersOrStrings {
+                            operator: "+",
+                            span,
+                        }));
+                    }
+                },
+                OpCode::Subtract => {
+                    self.binary_op("-", |a, b| a.checked_sub(b).map(Value::Number))?
+                }
+                OpCode::Multiply => {
+                    self.binary_op("*", |a, b| a.checked_mul(b).map(Value::Number))?
+                }
+                OpCode::Divide => {
+                    self.binary_op("/", |a, b| a.checked_div(b).map(Value::Number))?
+                }
+                OpCode::Modulo => {
+                    self.binary_op("%", |a, b| a.checked_rem(b).map(Value::Number))?
+                }
+                OpCode::Power => {
+                    self.binary_op("**", |a, b| a.checked_pow(b).map(Value::Number))?
+                }
+                OpCode::BitAnd => self.binary_op("&", |a, b| a.bit_and(b).map(Value::Number))?,
+                OpCode::BitOr => self.binary_op("|", |a, b| a.bit_or(b).map(Value::Number))?,
+                OpCode::BitXor => self.binary_op("^", |a, b| a.bit_xor(b).map(Value::Number))?,
+                OpCode::ShiftLeft => self.binary_op("<<", |a, b| a.shl(b).map(Value::Number))?,
+                OpCode::ShiftRight => self.binary_op(">>", |a, b| a.shr(b).map(Value::Number))?,
+                OpCode::Not => {
+                    let value = self.pop();
+                    self.push(Value::Bool(!value.is_truthy()));
+                }
+                OpCode::Negate => {
+                    let Value::Number(num) = self.peek(0) else {
+                        return Err(self.error(|span| VmError::OperandMustBeNumber {
+                            operator: "-",
+                            span,
+                        }));
+                    };
+
+                    let num = -num.clone();
+
+                    self.pop();
+                    self.push(Value::Number(num));
+                }
+                OpCode::BitNot => {
+                    let Value::Number(num) = self.peek(0) else {
+                        return Err(self.error(|span| VmError::OperandMustBeNumber {
+                            operator: "~",
+                            span,
+                        }));
+                    };
+
+                    let num = num
+                        .clone()
+                        .bit_not()
+                        .map_err(|error| self.error(|span| VmError::Arithmetic { error, span }))?;
+
+                    self.pop();
+                    self.push(Value::Number(num));
+                }
+                OpCode::Stringify => {
+                    let value = self.pop();
+
+                    self.push(Value::String(value.to_string().into()));
+                }
+                OpCode::Print => {
+                    let value = self.pop();
+
+                    println!("{value}");
+                }
+                OpCode::Jump => {
+                    let offset = self.read_short();
+                    self.frame_mut().ip += offset;
+                }
+                OpCode::JumpIfFalse => {
+                    let offset = self.read_short();
+
+                    if !self.peek(0).is_truthy() {
+                        self.frame_mut().ip += offset;
+                    }
+                }
+                OpCode::JumpIfNil => {
+                    let offset = self.read_short();
+
+                    if matches!(self.peek(0), Value::Nil) {
+                        self.frame_mut().ip += offset;
+                    }
+                }
+                OpCode::JumpIfProvided => {
+                    let slot = self.read_byte();
+                    let offset = self.read_short();
+
+                    if !self.frame().missing.contains(&slot) {
+                        self.frame_mut().ip += offset;
+                    }
+                }
+                OpCode::Loop => {
+                    let offset = self.read_short();
+                    self.frame_mut().ip -= offset;
+                }
+                OpCode::Call => {
+                    let argument_count = self.read_byte() as usize;
+
+                    self.call_value(
+                        self.peek(argument_count).clone(),
+                        argument_count,
+                        Vec::new(),
+                    )?;
+                }
+                OpCode::CallWith => {
+                    let argument_count = self.read_byte() as usize;
+                    let arguments = self.stack.split_off(self.stack.len() - argument_count);
+
+                    let mut positional = Vec::new();
+                    let mut named = Vec::new();
+
+                    for argument in arguments {
+                        match ArgumentKind::try_from(self.read_byte()) {
+                            Ok(ArgumentKind::Positional) => positional.push(argument),
+                            Ok(ArgumentKind::Spread) => match argument {
+                                Value::List(list) => positional.extend(list.iter().cloned()),
+                                _ => {
+                                    return Err(Error::Vm(VmError::SpreadMustBeList {
+                                        span: self.operand_span(),
+                                    }));
+                                }
+                            },
+                            Ok(ArgumentKind::Named) => {
+                                named.push((self.read_string(true), argument, self.operand_span()))
+                            }
+                            Err(byte) => {
+                                return Err(
+                                    self.error(|span| VmError::InvalidBytecode { byte, span })
+                                );
+                            }
+                        }
+                    }
+
+                    let argument_count = positional.len();
+
+                    self.stack.extend(positional);
+                    self.call_value(self.peek(argument_count).clone(), argument_count, named)?;
+                }
+                OpCode::Closure | OpCode::ClosureLong => {
+                    let Value::Function(function) =
+                        self.read_constant(op_code == OpCode::ClosureLong)
+                    else {
+                        return Err(self.error(|span| VmError::InvalidBytecode {
+                            byte: op_code as u8,
+                            span,
+                        }));
+                    };
+
+                    let mut upvalues = Vec::with_capacity(function.upvalue_count);
+
+                    for _ in 0..function.upvalue_count {
+                        let is_local = self.read_byte() == 1;
+                        let index = self.read_byte() as usize;
+
+                        upvalues.push(if is_local {
+                            self.capture_upvalue(self.frame().slots + index)
+                        } else {
+                            self.frame().closure.upvalues[index].clone()
+                        });
+                    }
+
+                    self.push(Value::Closure(Rc::new(Closure { function, upvalues })));
+                }
+                OpCode::CloseUpvalue => {
+                    self.close_upvalues(self.stack.len() - 1);
+                    self.pop();
+                }
+                OpCode::Return => {
+                    let result = self.pop();
+                    let slots = self.frame().slots;
+
+                    self.close_upvalues(slots);
+                    self.frames.pop();
+
+                    self.stack.truncate(slots);
+
+                    if self.frames.is_empty() {
+                        return Ok(());
+                    }
+
+                    self.push(result);
+                }
+                OpCode::Class | OpCode::ClassLong => {
+                    let name = self.read_string(op_code == OpCode::ClassLong);
+
+                    self.push(Value::Class(Rc::new(RefCell::new(Class {
+                        name: name.to_string(),
+                        superclass: None,
+                        methods: HashMap::new(),
+                    }))));
+                }
+                OpCode::Inherit | OpCode::InheritLong => {
+                    let name = self.read_string(op_code == OpCode::InheritLong);
+
+                    let Value::Class(superclass) = self.peek(1).clone() else {
+                        return Err(self.error(|span| VmError::SuperclassMustBeClass {
+                            name: name.to_string(),
+                            span,
+                        }));
+                    };
+
+                    if let Value::Class(subclass) = self.peek(0) {
+                        let methods = superclass.borrow().methods.clone();
+
+                        let mut subclass = subclass.borrow_mut();
+                        subclass.methods.extend(methods);
+                        subclass.superclass = Some(superclass);
+                    }
+
+                    self.pop();
+                }
+                OpCode::Method | OpCode::MethodLong => {
+                    let name = self.read_string(op_code == OpCode::MethodLong);
+
+                    if let (Value::Class(class), Value::Closure(method)) =
+                        (self.peek(1), self.peek(0))
+                    {
+                        class
+                            .borrow_mut()
+                            .methods
+                            .insert(name.to_string(), method.clone());
+                    }
+
+                    self.pop();
+                }
+            }
+        }
+    }
+
+    fn call_value(
+        &mut self,
+        callee: Value,
+        argument_count: usize,
+        named: Vec<(Rc<str>, Value, Span)>,
+    ) -> Result<()> {
+        let callee_slot = self.stack.len() - argument_count - 1;
+
+        match callee {
+            Value::Closure(closure) => self.call(closure, argument_count, named),
+            Value::Native(native) => {
+                if let Some((name, _, span)) = named.first() {
+                    return Err(Error::Vm(VmError::UnknownParameter {
+                        name: name.to_string(),
+                        span: *span,
+                    }));
+                }
+
+                if argument_count != native.arity {
+                    return Err(self.error(|span| VmError::ArityMismatch {
+                        expected: native.arity,
+                        got: argument_count,
+                        span,
+                    }));
+                }
+
+                let result = (native.function)(&self.stack[callee_slot + 1..])
+                    .map_err(|error| self.error(|span| VmError::Native { error, span }))?;
+
+                self.stack.truncate(callee_slot);
+                self.push(result);
+
+                Ok(())
+            }
+            Value::Class(class) => {
+                self.stack[callee_slot] = Value::Instance(Rc::new(RefCell::new(Instance {
+                    class: class.clone(),
+                    fields: HashMap::new(),
+                })));
+
+                let initializer = class.borrow().methods.get("init").cloned();
+
+                match initializer {
+                    Some(initializer) => self.call(initializer, argument_count, named),
+                    None if argument_count != 0 || !named.is_empty() => {
+                        Err(self.error(|span| VmError::ArityMismatch {
+                            expected: 0,
+                            got: argument_count + named.len(),
+                            span,
+                        }))
+                    }
+                    None => Ok(()),
+                }
+            }
+            Value::BoundMethod(bound) => {
+                self.stack[callee_slot] = bound.receiver.clone();
+
+                self.call(bound.method.clone(), argument_count, named)
+            }
+            _ => Err(self.error(|span| VmError::NotCallable { span })),
+        }
+    }
+
+    fn call(
+        &mut self,
+        closure: Rc<Closure>,
+        argument_count: usize,
+        named: Vec<(Rc<str>, Value, Span)>,
+    ) -> Result<()> {
+        if self.frames.len() == FRAMES_MAX {
+            return Err(self.error(|span| VmError::StackOverflow { span }));
+        }
+
+        let function = closure.function.clone();
+
+        let missing =
+            if named.is_empty() && !function.variadic && argument_count == function.params.len() {
+                Vec::new()
+            } else {
+                self.bind_arguments(&function, argument_count, named)?
+            };
+
+        let slot_count = function.params.len() + usize::from(function.variadic);
+
+        self.frames.push(CallFrame {
+            closure,
+            ip: 0,
+            start: 0,
+            slots: self.stack.len() - slot_count - 1,
+            missing,
+        });
+
+        Ok(())
+    }
+
+    fn bind_arguments(
+        &mut self,
+        function: &Function,
+        argument_count: usize,
+        named: Vec<(Rc<str>, Value, Span)>,
+    ) -> Result<Vec<u8>> {
+        let params = &function.params;
+
+        let mut positional = self
+            .stack
+            .split_off(self.stack.len() - argument_count)
+            .into_iter();
+        let mut values: Vec<Option<Value>> = params.iter().map(|_| positional.next()).collect();
+        let rest: Vec<Value> = positional.collect();
+
+        if !function.variadic && !rest.is_empty() {
+            return Err(self.error(|span| VmError::TooManyArguments {
+                max: params.len(),
+                got: argument_count,
+                span,
+            }));
+        }
+
+        for (name, value, span) in named {
+            let Some(index) = params.iter().position(|param| *param.name == *name) else {
+                return Err(Error::Vm(VmError::UnknownParameter {
+                    name: name.to_string(),
+                    span,
+                }));
+            };
+
+            if values[index].is_some() {
+                return Err(Error::Vm(VmError::DuplicateArgument {
+                    name: name.to_string(),
+                    span,
+                }));
+            }
+
+            values[index] = Some(value);
+        }
+
+        if let Some(param) = params
+            .iter()
+            .zip(&values)
+            .find_map(|(param, value)| (value.is_none() && !param.has_default).then_some(param))
+        {
+            return Err(self.error(|span| VmError::MissingArgument {
+                parameter: param.name.clone(),
+                span,
+            }));
+        }
+
+        let mut missing = Vec::new();
+
+        for (value, slot) in values.into_iter().zip(1..) {
+            match value {
+                Some(value) => self.push(value),
+                None => {
+                    missing.push(slot);
+                    self.push(Value::Nil);
+                }
+            }
+        }
+
+        if function.variadic {
+            self.push(Value::List(Rc::new(rest)));
+        }
+
+        Ok(missing)
+    }
+
+    fn bind_method(&mut self, class: &Rc<RefCell<Class>>, name: &str) -> Result<()> {
+        let Some(method) = class.borrow().methods.get(name).cloned() else {
+            return Err(self.error(|span| VmError::UndefinedProperty {
+                name: name.into(),
+                span,
+            }));
+        };
+
+        let receiver = self.pop();
+
+        self.push(Value::BoundMethod(Rc::new(BoundMethod {
+            receiver,
+            method,
+        })));
+
+        Ok(())
+    }
+
+    fn capture_upvalue(&mut self, slot: usize) -> Rc<RefCell<Upvalue>> {
+        if let Some(upvalue) = self
+            .open_upvalues
+            .iter()
+            .find(|upvalue| matches!(*upvalue.borrow(), Upvalue::Open(open) if open == slot))
+        {
+            return upvalue.clone();
+        }
+
+        let upvalue = Rc::new(RefCell::new(Upvalue::Open(slot)));
+
+        self.open_upvalues.push(upvalue.clone());
+
+        upvalue
+    }
+
+    fn close_upvalues(&mut self, last: usize) {
+        let stack = &self.stack;
+
+        self.open_upvalues.retain(|upvalue| {
+            let mut upvalue = upvalue.borrow_mut();
+
+            match *upvalue {
+                Upvalue::Open(slot) if slot >= last => {
+                    *upvalue = Upvalue::Closed(stack[slot].clone());
+                    false
+                }
+                _ => true,
+            }
+        });
+    }
+
+    fn binary_op(
+        &mut self,
+        operator: &'static str,
+        op: fn(Number, Number) -> std::result::Result<Value, ArithmeticError>,
+    ) -> Result<()> {
+        let (Value::Number(a), Value::Number(b)) = (self.peek(1), self.peek(0)) else {
+            return Err(self.error(|span| VmError::OperandsMustBeNumbers { operator, span }));
+        };
+
+        let result = op(a.clone(), b.clone())
+            .map_err(|error| self.error(|span| VmError::Arithmetic { error, span }))?;
+
+        self.pop();
+        self.pop();
+        self.push(result);
+
+        Ok(())
+    }
+
+    fn read_byte(&mut self) -> u8 {
+        let frame = self.frame_mut();
+        let byte = frame.closure.function.chunk.code()[frame.ip];
+
+        frame.ip += 1;
+
+        byte
+    }
+
+    fn read_short(&mut self) -> usize {
+        u16::from_be_bytes([self.read_byte(), self.read_byte()]) as usize
+    }
+
+    fn read_constant(&mut self, long: bool) -> Value {
+        let index = if long {
+            self.read_short()
+        } else {
+            self.read_byte() as usize
+        };
+
+        self.frame().closure.function.chunk.constants()[index].clone()
+    }
+
+    fn read_string(&mut self, long: bool) -> Rc<str> {
+        match self.read_constant(long) {
+            Value::String(s) => s,
+            value => value.to_string().into(),
+        }
+    }
+
+    fn push(&mut self, value: Value) {
+        self.stack.push(value);
+    }
+
+    fn pop(&mut self) -> Value {
+        self.stack.pop().unwrap_or(Value::Nil)
+    }
+
+    fn peek(&self, distance: usize) -> &Value {
+        &self.stack[self.stack.len() - 1 - distance]
+    }
+
+    fn frame(&self) -> &CallFrame {
+        self.frames
+            .last()
+            .expect("the VM always runs inside a call frame")
+    }
+
+    fn frame_mut(&mut self) -> &mut CallFrame {
+        self.frames
+            .last_mut()
+            .expect("the VM always runs inside a call frame")
+    }
+
+    fn error(&self, error: impl FnOnce(Span) -> VmError) -> Error {
+        let span = self
+            .frames
+            .last()
+            .map(|frame| frame.closure.function.chunk.span(frame.start))
+            .unwrap_or_default();
+
+        Error::Vm(error(span))
+    }
+
+    fn operand_span(&self) -> Span {
+        let frame = self.frame();
+
+        frame.closure.function.chunk.span(frame.ip - 1)
+    }
+}
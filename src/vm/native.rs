@@ -0,0 +1,63 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{error::NativeError, number::Number};
+
+use super::{NativeFunction, Value};
+
+pub fn natives() -> Vec<NativeFunction> {
+    vec![
+        NativeFunction {
+            name: "clock",
+            arity: 0,
+            function: clock,
+        },
+        NativeFunction {
+            name: "len",
+            arity: 1,
+            function: len,
+        },
+        NativeFunction {
+            name: "get",
+            arity: 2,
+            function: get,
+        },
+    ]
+}
+
+fn clock(_arguments: &[Value]) -> Result<Value, NativeError> {
+    let elapsed = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+
+    Ok(Value::Number(Number::Float(elapsed.as_secs_f64())))
+}
+
+fn len(arguments: &[Value]) -> Result<Value, NativeError> {
+    let Value::List(list) = &arguments[0] else {
+        return Err(NativeError::ExpectedList { native: "len" });
+    };
+
+    Ok(Value::Number(Number::Integer(list.len() as i64)))
+}
+
+fn get(arguments: &[Value]) -> Result<Value, NativeError> {
+    let Value::List(list) = &arguments[0] else {
+        return Err(NativeError::ExpectedList { native: "get" });
+    };
+
+    let Value::Number(index @ (Number::Integer(_) | Number::Big(_))) = &arguments[1] else {
+        return Err(NativeError::IndexNotInteger);
+    };
+
+    let element = match index {
+        Number::Integer(i) => usize::try_from(*i).ok().and_then(|i| list.get(i)),
+        _ => None,
+    };
+
+    element
+        .cloned()
+        .ok_or_else(|| NativeError::IndexOutOfRange {
+            index: index.clone(),
+            len: list.len(),
+        })
+}
@@ -0,0 +1,82 @@
+use std::{cell::RefCell, collections::HashMap, fmt::Display, rc::Rc};
+
+use crate::{chunk::Chunk, error::NativeError};
+
+use super::Value;
+
+#[derive(Debug)]
+pub struct Parameter {
+    pub name: String,
+    pub has_default: bool,
+}
+
+#[derive(Debug, Default)]
+pub struct Function {
+    pub name: Option<String>,
+    pub params: Vec<Parameter>,
+    pub variadic: bool,
+    pub upvalue_count: usize,
+    pub chunk: Chunk,
+}
+
+impl Display for Function {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "<fn {name}>"),
+            None => write!(f, "<script>"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Upvalue {
+    Open(usize),
+    Closed(Value),
+}
+
+#[derive(Debug)]
+pub struct Closure {
+    pub function: Rc<Function>,
+    pub upvalues: Vec<Rc<RefCell<Upvalue>>>,
+}
+
+pub struct NativeFunction {
+    pub name: &'static str,
+    pub arity: usize,
+    pub function: fn(&[Value]) -> Result<Value, NativeError>,
+}
+
+impl std::fmt::Debug for NativeFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "NativeFunction({})", self.name)
+    }
+}
+
+#[derive(Debug)]
+pub struct Class {
+    pub name: String,
+    pub superclass: Option<Rc<RefCell<Class>>>,
+    pub methods: HashMap<String, Rc<Closure>>,
+}
+
+impl Class {
+    pub fn inherits_from(&self, class: &Rc<RefCell<Class>>) -> bool {
+        std::ptr::eq(self, class.as_ptr())
+            || self
+                .superclass
+                .as_ref()
+                .is_some_and(|superclass| superclass.borrow().inherits_from(class))
+    }
+}
+
+#[derive(Debug)]
+pub struct Instance {
+    pub class: Rc<RefCell<Class>>,
+    pub fields: HashMap<String, Value>,
+}
+
+#[derive(Debug)]
+pub struct BoundMethod {
+    pub receiver: Value,
+    pub method: Rc<Closure>,
+}
@@ -0,0 +1,75 @@
+use std::{cell::RefCell, fmt::Display, rc::Rc};
+
+use crate::number::Number;
+
+use super::{BoundMethod, Class, Closure, Function, Instance, NativeFunction};
+
+#[derive(Debug, Clone)]
+pub enum Value {
+    Number(Number),
+    String(Rc<str>),
+    Bool(bool),
+    Nil,
+    Function(Rc<Function>),
+    Closure(Rc<Closure>),
+    Native(Rc<NativeFunction>),
+    Class(Rc<RefCell<Class>>),
+    Instance(Rc<RefCell<Instance>>),
+    BoundMethod(Rc<BoundMethod>),
+    List(Rc<Vec<Value>>),
+}
+
+impl Value {
+    pub fn is_truthy(&self) -> bool {
+        !matches!(self, Self::Nil | Self::Bool(false))
+    }
+
+    pub fn is_equal(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Number(a), Self::Number(b)) => a == b,
+            (Self::String(a), Self::String(b)) => a == b,
+            (Self::Bool(a), Self::Bool(b)) => a == b,
+            (Self::Nil, Self::Nil) => true,
+            (Self::Function(a), Self::Function(b)) => Rc::ptr_eq(a, b),
+            (Self::Closure(a), Self::Closure(b)) => Rc::ptr_eq(a, b),
+            (Self::Native(a), Self::Native(b)) => Rc::ptr_eq(a, b),
+            (Self::Class(a), Self::Class(b)) => Rc::ptr_eq(a, b),
+            (Self::Instance(a), Self::Instance(b)) => Rc::ptr_eq(a, b),
+            (Self::BoundMethod(a), Self::BoundMethod(b)) => Rc::ptr_eq(a, b),
+            (Self::List(a), Self::List(b)) => Rc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Number(num) => write!(f, "{num}"),
+            Self::String(s) => write!(f, "{s}"),
+            Self::Bool(val) => write!(f, "{val}"),
+            Self::Nil => write!(f, "nil"),
+            Self::Function(function) => write!(f, "{function}"),
+            Self::Closure(closure) => write!(f, "{}", closure.function),
+            Self::Native(_) => write!(f, "<native fn>"),
+            Self::Class(class) => write!(f, "{}", class.borrow().name),
+            Self::Instance(instance) => {
+                write!(f, "{} instance", instance.borrow().class.borrow().name)
+            }
+            Self::BoundMethod(bound) => write!(f, "{}", bound.method.function),
+            Self::List(list) => {
+                write!(f, "[")?;
+
+                for (i, value) in list.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+
+                    write!(f, "{value}")?;
+                }
+
+                write!(f, "]")
+            }
+        }
+    }
+}